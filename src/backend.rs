@@ -1,15 +1,17 @@
 use std::collections::BTreeMap;
-use std::collections::HashSet;
-use std::num::ParseIntError;
+use std::fmt;
 use std::str::FromStr;
 use std::time::{UNIX_EPOCH, Duration};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 use chrono::prelude::DateTime;
-use chrono::{Utc};
-use chrono::FixedOffset;
+use chrono::{Local, Offset, SecondsFormat, TimeZone, Timelike, Utc};
+use chrono::{Datelike, FixedOffset, NaiveDate};
+use chrono_tz::Tz;
 
-use crate::display::{WeatherForecast, WeatherSection};
+use crate::display::{AirQuality, CurrentConditions, WeatherForecast, WeatherSection};
+use crate::filter::HourFilter;
+use crate::request::{ApiError, Location};
 
 /*
    Snippet of JSON data
@@ -18,19 +20,39 @@ use crate::display::{WeatherForecast, WeatherSection};
    main: {
      temp: 92.41,
      feels_like: 90.88,
+     temp_min: 91.06,
+     temp_max: 92.41,
+     pressure: 1011,
+     sea_level: 1011,
+     grnd_level: 927,
      humidity: 30
    },
-   weather: {
+   weather: [{
+     id: 500,
      main: "Rain",
      description: "light rain",
+     icon: "10d"
+   }, ...],
+   clouds: {
+     all: 75
    },
    wind: {
      speed: 21.43,
-     deg: 236
+     deg: 236,
+     gust: 21.74
    },
+   visibility: 10000,
+   pop: 0.45,
    rain: {
      3h: 0.22
    },
+   snow: {
+     3h: 0.0      (when snowing, in place of or alongside rain)
+   },
+   sys: {
+     pod: "d"
+   },
+   dt_txt: "2022-06-18 21:00:00"
 */
 
 /*
@@ -52,53 +74,264 @@ impl WeatherList {
         serde_json::from_str(response)
     }
 
-    // transforms raw weather list into weather forecast
-    pub(crate) fn transform(&self) -> WeatherForecast {
-        let mut map: BTreeMap<DayKey, Vec<WeatherSection>> = BTreeMap::new();
-        let exclude_hours: Vec<u8> = vec![0, 3];
-        let exclusion: HashSet<u8> = exclude_hours.into_iter().collect();  
+    // Coordinates of the forecast city, e.g. for coordinate only endpoints
+    #[inline]
+    pub fn location(&self) -> Location {
+        self.city.location()
+    }
+
+    // transforms raw weather list into weather forecast,
+    // grouping days and hours by the given clock
+    pub fn transform(&self, clock: Clock, filter: &HourFilter) -> Result<WeatherForecast, ApiError> {
+        let list: Vec<(DayKey, u8, WeatherSection)> = self.list.iter()
+            .map(|wd| wd.transform(&clock, self.city.timezone))
+            .collect::<Result<_, _>>()?;
+
+        let map = group_days(list, Some(filter), self.city.daylight_hours(&clock));
 
-        let list: Vec<(DayKey, u8, WeatherSection)> = self.list.iter().map(WeatherData::transform).collect();
+        Ok(WeatherForecast::new(self.city.clone(), clock, map))
+    }
+}
 
-        // merge only those hours not on the exclusion list
-        // store WeatherSection by day key
-        map = list.into_iter().fold(map, |mut acc, (day, hour, ws)| {
-            if !exclusion.contains(&hour) {
-                acc.entry(day)
-                    .and_modify(|v| v.push(ws.clone()))
-                    .or_insert_with(|| vec![ws.clone()]);
+// Shared by every granularity, merge only those hours the filter keeps
+// and store WeatherSection by day key
+pub(crate) fn group_days(list: Vec<(DayKey, u8, WeatherSection)>, filter: Option<&HourFilter>, daylight: (u8, u8))
+                         -> BTreeMap<DayKey, Vec<WeatherSection>> {
+    let map: BTreeMap<DayKey, Vec<WeatherSection>> = BTreeMap::new();
+
+    list.into_iter().fold(map, |mut acc, (day, hour, ws)| {
+        if filter.is_none_or(|f| f.keep(hour, Some(daylight))) {
+            acc.entry(day).or_default().push(ws);
+        }
+
+        acc
+    })
+}
+
+// Unix start and end times a section's precipitation volumes accumulated over
+pub(crate) type Span = (u64, u64);
+
+const THREE_HOURS: u64 = 3 * 3600;
+
+// Rolls finer sections into 3 hour ones laid out like the OpenWeatherMap
+// forecast, whose volumes are totals for the 3 hours up to each section.
+// Sections on a 3 hour boundary are kept, taking the share of every volume
+// accumulated within their window (hourly amounts, or part of a longer one)
+// along with the temperature range and highest chance of rain over it.
+// Where steps are further apart, e.g. met.no's later 6 hour ones, a window
+// reaches back to the previous kept section so no amount is left out
+pub(crate) fn three_hourly(list: Vec<(DayKey, u8, WeatherSection, Span)>) -> Vec<(DayKey, u8, WeatherSection)> {
+    let mut previous = None;
+
+    list.iter()
+        .filter(|(_, _, ws, _)| ws.timestamp % THREE_HOURS == 0)
+        .map(|(day, hour, ws, _)| {
+            let start = previous.replace(ws.timestamp).unwrap_or_else(|| ws.timestamp.saturating_sub(THREE_HOURS));
+            let window = (start, ws.timestamp);
+            let mut section = ws.clone();
+            let (mut rain, mut snow, mut precipitation) = (None, None, 0.0);
+
+            for (_, _, other, span) in &list {
+                let share = overlap(*span, window);
+
+                if share > 0.0 {
+                    rain = accumulate(rain, other.rain_volume, share);
+                    snow = accumulate(snow, other.snow_volume, share);
+                    precipitation += other.precipitation * share;
+                }
+
+                if window.0 < other.timestamp && other.timestamp <= window.1 {
+                    section.temp_min = section.temp_min.min(other.temp_min);
+                    section.temp_max = section.temp_max.max(other.temp_max);
+                    section.pop = section.pop.max(other.pop);
+                }
             }
 
-            acc
-        });
+            let primary = section.conditions.first().map(|c| c.id).unwrap_or_default();
+
+            section.rain_volume = rain;
+            section.snow_volume = snow;
+            section.precipitation = precipitation;
+            section.accumulation = ws.accumulation.map(|_| window.1 - window.0);
+            section.precip_type = Precipitation::detect(primary, rain, snow);
+
+            (*day, *hour, section)
+        })
+        .collect()
+}
 
-        WeatherForecast::new(self.city.clone(), map)
+// Fraction of the span falling within the window
+fn overlap(span: Span, window: Span) -> f32 {
+    let covered = span.1.min(window.1).saturating_sub(span.0.max(window.0));
+
+    match span.1.saturating_sub(span.0) {
+        0 => 0.0,
+        length => covered as f32 / length as f32,
+    }
+}
+
+#[inline]
+fn accumulate(total: Option<f32>, volume: Option<f32>, share: f32) -> Option<f32> {
+    match volume {
+        Some(v) => Some(total.unwrap_or_default() + v * share),
+        None => total,
+    }
+}
+
+
+// Time resolution of the forecast, 3h uses the forecast endpoint
+// while the others come from the One Call api
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    Minutely,
+    Hourly,
+    #[default]
+    #[serde(rename = "3h")]
+    ThreeHour,
+    Daily,
+}
+
+impl FromStr for Granularity {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minutely" => Ok(Self::Minutely),
+            "hourly" => Ok(Self::Hourly),
+            "3h" => Ok(Self::ThreeHour),
+            "daily" => Ok(Self::Daily),
+            _ => Err(ApiError::BadParse("unknown granularity"))
+        }
     }
 }
 
+impl fmt::Display for Granularity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Minutely => write!(f, "Next hour"),
+            Self::Hourly => write!(f, "Hourly"),
+            Self::ThreeHour => write!(f, "3 hour"),
+            Self::Daily => write!(f, "Daily"),
+        }
+    }
+}
 
-// Simple struct that serves as an accurate key to group weather sections
+
+// Selects which clock forecast times are grouped and shown in
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Clock {
+    Utc,
+    Local,
+    #[default]
+    City,
+    Zone(Tz),
+}
+
+impl FromStr for Clock {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "utc" => Ok(Self::Utc),
+            "local" => Ok(Self::Local),
+            "city" => Ok(Self::City),
+            _ => s.parse::<Tz>()
+                .map(Self::Zone)
+                .map_err(|_| ApiError::BadParse("unknown timezone, expected utc, local, city or an IANA name"))
+        }
+    }
+}
+
+impl fmt::Display for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Utc => write!(f, "UTC"),
+            Self::Local => write!(f, "local time"),
+            Self::City => write!(f, "city time"),
+            Self::Zone(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+impl Clock {
+    // Converts unix timestamp into a datetime on this clock,
+    // city_offset is the city's utc offset in seconds
+    pub fn localize(&self, value: u64, city_offset: i32) -> DateTime<FixedOffset> {
+        let system_time = UNIX_EPOCH + Duration::from_secs(value);
+        let utc = DateTime::<Utc>::from(system_time);
+
+        let offset: FixedOffset = match self {
+            Self::Utc => FixedOffset::east(0),
+            Self::Local => Local.offset_from_utc_datetime(&utc.naive_utc()).fix(),
+            Self::City => FixedOffset::east(city_offset),
+            Self::Zone(tz) => tz.offset_from_utc_datetime(&utc.naive_utc()).fix(),
+        };
+
+        utc.with_timezone(&offset)
+    }
+}
+
+
+// Simple struct that serves as an accurate key to group weather sections,
+// holds a full calendar date so days order correctly across year boundaries
 #[derive(Copy, Clone, Debug, Hash, Eq, Ord, PartialOrd, PartialEq)]
 pub struct DayKey {
-    month: u8,
-    day: u8,
+    date: NaiveDate,
 }
 
-impl ToString for DayKey {
-    fn to_string(&self) -> String {
-        format!("{:02}-{:02}", self.month, self.day)
+impl DayKey {
+    // Returns None for an invalid calendar date
+    pub fn new(year: i32, month: u32, day: u32) -> Option<DayKey> {
+        NaiveDate::from_ymd_opt(year, month, day).map(|date| DayKey { date })
+    }
+
+    #[inline]
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    #[inline]
+    pub fn year(&self) -> i32 {
+        self.date.year()
+    }
+
+    #[inline]
+    pub fn month(&self) -> u32 {
+        self.date.month()
+    }
+
+    #[inline]
+    pub fn day(&self) -> u32 {
+        self.date.day()
     }
 }
 
+impl From<NaiveDate> for DayKey {
+    fn from(date: NaiveDate) -> Self {
+        DayKey { date }
+    }
+}
+
+impl fmt::Display for DayKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.date.format("%Y-%m-%d"))
+    }
+}
+
+// Serialized as its YYYY-MM-DD display form
+impl Serialize for DayKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+// Parses YYYY-MM-DD, returning an error rather than panicking on malformed input
 impl FromStr for DayKey {
-    type Err = ParseIntError;
+    type Err = chrono::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let tokens: Vec<&str> = s.split('-').collect();
-        let month = tokens[0].parse::<u8>()?;
-        let day = tokens[1].parse::<u8>()?;
-
-        Ok(DayKey { month, day })
+        NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").map(DayKey::from)
     }
 }
 
@@ -108,38 +341,67 @@ struct WeatherData {
     #[serde(rename = "dt")] 
     datetime: u64,
     main: Temperature,
-    weather: Vec<Description>,
+    weather: Vec<Condition>,
+    clouds: Clouds,
     wind: Wind,
+    visibility: Option<u32>,
+    #[serde(default)]
+    pop: f32,
     rain: Option<Rain>,
+    snow: Option<Snow>,
+    sys: Sys,
+    dt_txt: String,
 }
 
 impl WeatherData {
     // Flatten WeatherData and transform into WeatherSection
-    pub fn transform(&self) -> (DayKey, u8, WeatherSection) {
+    // using the clock's local date and hour
+    pub fn transform(&self, clock: &Clock, city_offset: i32) -> Result<(DayKey, u8, WeatherSection), ApiError> {
         // datetime
-        let system_time = UNIX_EPOCH + Duration::from_secs(self.datetime);
-        let datetime = DateTime::<Utc>::from(system_time);
+        let datetime = clock.localize(self.datetime, city_offset);
 
         // Formats the combined date and time with the specified format string.
         let timestamp: String = datetime.format("%Y-%m-%d %H:%M").to_string();
         let day_of_week: String = datetime.format("%a").to_string();
-        let day_temp: String = datetime.format("%m-%d").to_string();
 
-        let day_key = DayKey::from_str(&day_temp).unwrap();
-        let hour_int: u8 = datetime.format("%H").to_string().parse().unwrap();
+        let day_key = DayKey::from(datetime.naive_local().date());
+        let hour_int: u8 = datetime.hour() as u8;
+        // first condition is the primary one
+        let primary = self.weather.first().ok_or(ApiError::NoConditions(self.dt_txt.clone()))?;
+        let rain = self.rain.as_ref().map(|r| r.three_hour);
+        let snow = self.snow.as_ref().map(|s| s.three_hour);
 
-        (day_key, hour_int, WeatherSection::new(
+        Ok((day_key, hour_int, WeatherSection {
+            timestamp: self.datetime,
+            local: datetime,
             day_of_week,
-            hour_int,
-            timestamp,
-            self.main.temp,
-            self.main.feels_like,
-            self.main.humidity,
-            self.weather[0].description.clone(),
-            self.wind.speed,
-            self.wind.deg,
-            self.rain.as_ref().map(|r| r.three_hour),
-        ))
+            hour: hour_int,
+            datetime: timestamp,
+            dt_txt: self.dt_txt.clone(),
+            temp: self.main.temp,
+            feels_like: self.main.feels_like,
+            temp_min: self.main.temp_min,
+            temp_max: self.main.temp_max,
+            pressure: self.main.pressure,
+            sea_level: self.main.sea_level,
+            grnd_level: self.main.grnd_level,
+            humidity: self.main.humidity,
+            conditions: self.weather.clone(),
+            clouds: self.clouds.all,
+            visibility: self.visibility,
+            pop: self.pop,
+            wind_speed: self.wind.speed,
+            wind_deg: self.wind.deg,
+            wind_gust: self.wind.gust,
+            rain_volume: rain,
+            snow_volume: snow,
+            precipitation: rain.unwrap_or_default() + snow.unwrap_or_default(),
+            accumulation: Some(THREE_HOURS),
+            precip_type: Precipitation::detect(primary.id, rain, snow),
+            daytime: self.sys.pod == "d",
+            air_quality: None,
+            spread: None,
+        }))
     }
 }
 
@@ -147,18 +409,34 @@ impl WeatherData {
 struct Temperature {
     temp: f32,
     feels_like: f32,
+    temp_min: f32,
+    temp_max: f32,
+    pressure: u16,
+    sea_level: Option<u16>,
+    grnd_level: Option<u16>,
     humidity: u16,
 }
 
+// Single weather condition, a time slot may report several e.g. rain and mist
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct Condition {
+    pub(crate) id: u16,
+    #[serde(rename(deserialize = "main"))]
+    pub(crate) group: String,
+    pub(crate) description: String,
+    pub(crate) icon: String,
+}
+
 #[derive(Deserialize, Debug)]
-struct Description {
-    description: String,
+struct Clouds {
+    all: u8,
 }
 
 #[derive(Deserialize, Debug)]
 struct Wind {
     speed: f32,
     deg: u16,
+    gust: Option<f32>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -167,40 +445,571 @@ struct Rain {
     three_hour: f32,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Deserialize, Debug)]
+struct Snow {
+    #[serde(rename = "3h")] 
+    three_hour: f32,
+}
+
+// Kind of precipitation falling during a time slot
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Precipitation {
+    #[default]
+    None,
+    Rain,
+    Snow,
+    Mixed,
+}
+
+impl Precipitation {
+    // Detects type from the OpenWeatherMap condition id groups
+    // (2xx thunderstorm, 3xx drizzle, 5xx rain, 6xx snow),
+    // falling back to which volumes were reported
+    pub fn detect(condition_id: u16, rain: Option<f32>, snow: Option<f32>) -> Precipitation {
+        match condition_id {
+            // sleet, rain and snow, freezing rain
+            611..=616 | 511 => Self::Mixed,
+            200..=599 => if snow.is_some() { Self::Mixed } else { Self::Rain },
+            600..=699 => if rain.is_some() { Self::Mixed } else { Self::Snow },
+            _ => match (rain, snow) {
+                (Some(_), Some(_)) => Self::Mixed,
+                (Some(_), None) => Self::Rain,
+                (None, Some(_)) => Self::Snow,
+                (None, None) => Self::None,
+            }
+        }
+    }
+}
+
+impl fmt::Display for Precipitation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "-"),
+            Self::Rain => write!(f, "rain"),
+            Self::Snow => write!(f, "snow"),
+            Self::Mixed => write!(f, "mixed"),
+        }
+    }
+}
+
+// Part of day, "d" or "n"
+#[derive(Deserialize, Debug)]
+struct Sys {
+    pod: String,
+}
+
+// Current conditions payload from the weather endpoint,
+// shares the main, wind, clouds and condition shapes with forecast entries
+#[derive(Deserialize, Debug)]
+pub struct CurrentWeather {
+    #[serde(rename = "dt")]
+    datetime: u64,
+    name: String,
+    coord: Coord,
+    main: Temperature,
+    weather: Vec<Condition>,
+    clouds: Clouds,
+    wind: Wind,
+    visibility: Option<u32>,
+    rain: Option<RecentVolume>,
+    snow: Option<RecentVolume>,
+    sys: CurrentSys,
+    timezone: i32,
+}
+
+impl CurrentWeather {
+
+    #[inline]
+    pub fn parse(response: &str) -> Result<CurrentWeather, serde_json::Error> {
+        serde_json::from_str(response)
+    }
+
+    #[inline]
+    pub fn location(&self) -> Location {
+        Location::Coordinates(self.coord.lat as f64, self.coord.lon as f64)
+    }
+
+    // Rename the place e.g. after the geocoder's name for the coordinates
+    pub(crate) fn with_place(mut self, name: &str, country: &str) -> Self {
+        self.name = name.to_owned();
+        self.sys.country = Some(country.to_owned());
+        self
+    }
+
+    // City header details for forecasts lacking them e.g. One Call
+    pub fn city(&self) -> City {
+        City {
+            name: self.name.clone(),
+            country: self.sys.country.clone().unwrap_or_default(),
+            coord: self.coord,
+            sunrise: self.sys.sunrise,
+            sunset: self.sys.sunset,
+            timezone: self.timezone,
+            source: None,
+        }
+    }
+
+    // Flatten into current conditions shown on the given clock
+    pub fn transform(&self, clock: Clock) -> Result<CurrentConditions, ApiError> {
+        let datetime = clock.localize(self.datetime, self.timezone);
+
+        if self.weather.is_empty() {
+            return Err(ApiError::NoConditions(format!("{} current", self.name)))
+        }
+
+        Ok(CurrentConditions {
+            place: format!("{} {}", self.name, self.sys.country.as_deref().unwrap_or_default()).trim().to_owned(),
+            datetime: datetime.format("%a %m-%d %H:%M").to_string(),
+            temp: self.main.temp,
+            feels_like: self.main.feels_like,
+            temp_min: self.main.temp_min,
+            temp_max: self.main.temp_max,
+            pressure: self.main.pressure,
+            humidity: self.main.humidity,
+            conditions: self.weather.clone(),
+            clouds: self.clouds.all,
+            visibility: self.visibility,
+            wind_speed: self.wind.speed,
+            wind_deg: self.wind.deg,
+            wind_gust: self.wind.gust,
+            rain_one_hour: self.rain.as_ref().map(|r| r.one_hour),
+            snow_one_hour: self.snow.as_ref().map(|s| s.one_hour),
+            air_quality: None,
+        })
+    }
+}
+
+// Precipitation volume over the last hour
+#[derive(Deserialize, Debug)]
+struct RecentVolume {
+    #[serde(rename = "1h")]
+    one_hour: f32,
+}
+
+#[derive(Deserialize, Debug)]
+struct CurrentSys {
+    country: Option<String>,
+    sunrise: u64,
+    sunset: u64,
+}
+
+// Air pollution payload, same shape for current and forecast endpoints
+#[derive(Deserialize, Debug)]
+pub struct AirPollutionList {
+    list: Vec<AirPollutionData>,
+}
+
+impl AirPollutionList {
+
+    #[inline]
+    pub fn parse(response: &str) -> Result<AirPollutionList, serde_json::Error> {
+        serde_json::from_str(response)
+    }
+
+    // Air quality keyed by unix timestamp for merging into sections
+    pub fn transform(&self) -> BTreeMap<u64, AirQuality> {
+        self.list.iter().map(|a| (a.datetime, a.transform())).collect()
+    }
+
+    // Latest reading, the current endpoint returns a single entry
+    pub fn latest(&self) -> Option<AirQuality> {
+        self.list.iter().max_by_key(|a| a.datetime).map(AirPollutionData::transform)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct AirPollutionData {
+    #[serde(rename = "dt")]
+    datetime: u64,
+    main: AirIndex,
+    components: Components,
+}
+
+impl AirPollutionData {
+    fn transform(&self) -> AirQuality {
+        AirQuality {
+            aqi: self.main.aqi,
+            pm2_5: self.components.pm2_5,
+            pm10: self.components.pm10,
+            o3: self.components.o3,
+            no2: self.components.no2,
+            so2: self.components.so2,
+            co: self.components.co,
+        }
+    }
+}
+
+// Air quality index 1 (good) to 5 (very poor)
+#[derive(Deserialize, Debug)]
+struct AirIndex {
+    aqi: u8,
+}
+
+// Pollutant concentrations in μg/m3
+#[derive(Deserialize, Debug)]
+struct Components {
+    co: f32,
+    no2: f32,
+    o3: f32,
+    so2: f32,
+    pm2_5: f32,
+    pm10: f32,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct City {
     name: String,
     country: String,
     coord: Coord,
+    #[serde(serialize_with = "rfc3339")]
     sunrise: u64,
+    #[serde(serialize_with = "rfc3339")]
     sunset: u64,
+    // utc offset in seconds
+    #[serde(rename(serialize = "utc_offset"))]
     timezone: i32,
+    // provider that served the data, noted when failing over
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    source: Option<&'static str>,
 }
 
 
-#[derive(Copy, Clone, Deserialize, Debug)]
+#[derive(Copy, Clone, Deserialize, Serialize, Debug)]
 struct Coord {
     lat: f32,
     lon: f32,
 }
 
 
-impl ToString for City {
-    fn to_string(&self) -> String {
-        // datetime
-        let sunrise: String = datetime(self.sunrise, self.timezone);
-        let sunset: String = datetime(self.sunset, self.timezone);
+impl City {
+    // Header details for providers whose payload isn't shaped like OpenWeatherMap's
+    pub(crate) fn new(name: String, country: String, lat: f64, lon: f64, sunrise: u64, sunset: u64, timezone: i32) -> City {
+        City {
+            name,
+            country,
+            coord: Coord { lat: lat as f32, lon: lon as f32 },
+            sunrise,
+            sunset,
+            timezone,
+            source: None,
+        }
+    }
+
+    pub(crate) fn with_source(mut self, source: &'static str) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub(crate) fn with_place(mut self, name: &str, country: &str) -> Self {
+        self.name = name.to_owned();
+        self.country = country.to_owned();
+        self
+    }
+
+    #[inline]
+    pub fn location(&self) -> Location {
+        Location::Coordinates(self.coord.lat as f64, self.coord.lon as f64)
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    // Utc offset in seconds
+    #[inline]
+    pub fn timezone(&self) -> i32 {
+        self.timezone
+    }
+
+    // First and last whole hours of daylight on the given clock
+    pub fn daylight_hours(&self, clock: &Clock) -> (u8, u8) {
+        let sunrise = clock.localize(self.sunrise, self.timezone);
+        let sunset = clock.localize(self.sunset, self.timezone);
+
+        // round sunrise up so a slot before it is excluded
+        let rise = if sunrise.minute() > 0 { (sunrise.hour() + 1) % 24 } else { sunrise.hour() };
+
+        (rise as u8, sunset.hour() as u8)
+    }
+
+    // Location header with sunrise and sunset shown on the given clock
+    pub fn describe(&self, clock: &Clock) -> String {
+        let sunrise: String = datetime(self.sunrise, self.timezone, clock);
+        let sunset: String = datetime(self.sunset, self.timezone, clock);
+
+        let source = self.source.map(|s| format!("  via {}", s)).unwrap_or_default();
+
+        format!("{} {} [{}, {}]{}\nSunrise {}  Sunset {}  ({})", self.name, self.country, self.coord.lat, self.coord.lon, source, sunrise, sunset, clock)
+    }
+}
 
-        format!("{} {} [{}, {}]\nSunrise {}  Sunset {}", self.name, self.country, self.coord.lat, self.coord.lon, sunrise, sunset)
+impl fmt::Display for City {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.describe(&Clock::City))
     }
 }
 
+// Sunrise and sunset for providers that only flag periods as day or night,
+// the first switch into day then the switch back to night after it.
+// Falls back to the first timestamp when no switch is seen
+pub(crate) fn estimate_daylight(periods: impl Iterator<Item = (u64, bool)>) -> (u64, u64) {
+    let periods: Vec<(u64, bool)> = periods.collect();
+    let first = periods.first().map(|p| p.0).unwrap_or_default();
+
+    let switch = |daytime: bool, from: usize| periods.windows(2)
+        .enumerate()
+        .skip(from)
+        .find(|(_, w)| w[0].1 != daytime && w[1].1 == daytime)
+        .map(|(i, w)| (i + 1, w[1].0));
+
+    let (rise, sunrise) = switch(true, 0).unwrap_or((0, first));
+    let sunset = switch(false, rise).map(|(_, ts)| ts).unwrap_or(first);
+
+    (sunrise, sunset)
+}
+
+// Sunrise and sunset on the date from the sunrise equation, good to a
+// minute or two. None during polar day or night when the sun never crosses
+// the horizon
+pub(crate) fn solar_daylight(lat: f64, lon: f64, date: NaiveDate) -> Option<(u64, u64)> {
+    let lat = lat.to_radians();
+    let j2000 = NaiveDate::from_ymd(2000, 1, 1);
+
+    // mean solar noon, in julian days from J2000
+    let noon = (date - j2000).num_days() as f64 + 0.0009 - lon / 360.0;
+    let anomaly = (357.5291 + 0.985_600_28 * noon).rem_euclid(360.0).to_radians();
+    let center = 1.9148 * anomaly.sin() + 0.02 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+    let ecliptic = (anomaly.to_degrees() + center + 282.9372).rem_euclid(360.0).to_radians();
+    let transit = noon + 0.0053 * anomaly.sin() - 0.0069 * (2.0 * ecliptic).sin();
+    let declination = (ecliptic.sin() * 23.4397_f64.to_radians().sin()).asin();
+
+    // hour angle of the sun's upper limb meeting the horizon, with refraction
+    let cos_angle = ((-0.833_f64).to_radians().sin() - lat.sin() * declination.sin()) / (lat.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_angle) {
+        return None
+    }
+    let half_day = cos_angle.acos().to_degrees() / 360.0;
+
+    // J2000 is 10957.5 days after the unix epoch
+    let unix = |days: f64| ((days + 10957.5) * 86400.0).round() as u64;
+
+    Some((unix(transit - half_day), unix(transit + half_day)))
+}
+
+// Serializes a unix timestamp as an RFC 3339 UTC time e.g. 2022-06-18T17:00:00Z
+pub(crate) fn rfc3339<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&Clock::Utc.localize(*value, 0).with_timezone(&Utc).to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
 // Helper utility method
-pub fn datetime(value: u64, offset: i32) -> String {
-    let system_time = UNIX_EPOCH + Duration::from_secs(value);
-    let d = DateTime::<Utc>::from(system_time);
-    
-    let tz: FixedOffset = FixedOffset::east(offset);
-    let dtz = d.with_timezone(&tz);
-    dtz.format("%m-%d %H:%M").to_string()
+pub fn datetime(value: u64, offset: i32, clock: &Clock) -> String {
+    clock.localize(value, offset).format("%m-%d %H:%M").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::Preset;
+    use crate::mock_json::{JSON_CURRENT_RESPONSE, JSON_RESPONSE, JSON_RESPONSE_SNOW_MICRO};
+
+    // Days of the 40 Tucson sections on the clock, with each day's hours
+    fn grouped(clock: Clock) -> Vec<(String, Vec<u32>)> {
+        let forecast = WeatherList::parse(JSON_RESPONSE).unwrap()
+            .transform(clock, &HourFilter::from(Preset::All))
+            .unwrap();
+
+        forecast.days()
+            .map(|(day, sections)| (day.to_string(), sections.iter().map(|s| s.local.hour()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn groups_days_on_city_clock() {
+        let days = grouped(Clock::City);
+        let keys: Vec<&str> = days.iter().map(|(k, _)| k.as_str()).collect();
+
+        assert_eq!(keys, ["2022-06-18", "2022-06-19", "2022-06-20", "2022-06-21", "2022-06-22", "2022-06-23"]);
+        assert_eq!(days[0].1, [14, 17, 20, 23]);
+        assert_eq!(days[1].1, [2, 5, 8, 11, 14, 17, 20, 23]);
+        assert_eq!(days[5].1, [2, 5, 8, 11]);
+    }
+
+    #[test]
+    fn groups_days_on_zone_clock() {
+        let tokyo = grouped(Clock::Zone(chrono_tz::Asia::Tokyo));
+        let keys: Vec<&str> = tokyo.iter().map(|(k, _)| k.as_str()).collect();
+
+        assert_eq!(keys, ["2022-06-19", "2022-06-20", "2022-06-21", "2022-06-22", "2022-06-23", "2022-06-24"]);
+        assert_eq!(tokyo[0].1, [6, 9, 12, 15, 18, 21]);
+        assert_eq!(tokyo[5].1, [0, 3]);
+
+        // Tucson keeps MST all year, matching Los Angeles' PDT in June
+        assert_eq!(grouped(Clock::Zone(chrono_tz::America::Los_Angeles)), grouped(Clock::City));
+    }
+
+    #[test]
+    fn parses_day_key() {
+        let day: DayKey = "2022-06-18".parse().unwrap();
+
+        assert_eq!((day.year(), day.month(), day.day()), (2022, 6, 18));
+        assert_eq!(day, DayKey::new(2022, 6, 18).unwrap());
+    }
+
+    #[test]
+    fn rejects_malformed_day_key() {
+        for input in ["12", "2022-13-01", "", "2022-02-30", "18/06/2022"] {
+            assert!(input.parse::<DayKey>().is_err(), "{:?} parsed", input);
+        }
+    }
+
+    #[test]
+    fn day_key_display_round_trips() {
+        let day = DayKey::new(2023, 1, 5).unwrap();
+
+        assert_eq!(day.to_string(), "2023-01-05");
+        assert_eq!(day.to_string().parse::<DayKey>().unwrap(), day);
+    }
+
+    #[test]
+    fn day_keys_order_across_year_boundary() {
+        let mut days = BTreeMap::new();
+        days.insert("2023-01-01".parse::<DayKey>().unwrap(), "new year");
+        days.insert("2022-12-30".parse::<DayKey>().unwrap(), "end of year");
+
+        assert_eq!(days.into_values().collect::<Vec<_>>(), ["end of year", "new year"]);
+    }
+
+    #[test]
+    fn detects_precipitation_from_condition_id() {
+        // condition groups decide regardless of volumes
+        assert_eq!(Precipitation::detect(201, None, None), Precipitation::Rain);
+        assert_eq!(Precipitation::detect(301, Some(0.1), None), Precipitation::Rain);
+        assert_eq!(Precipitation::detect(500, None, None), Precipitation::Rain);
+        assert_eq!(Precipitation::detect(601, None, Some(1.0)), Precipitation::Snow);
+        assert_eq!(Precipitation::detect(511, Some(0.5), None), Precipitation::Mixed);
+        assert_eq!(Precipitation::detect(611, None, None), Precipitation::Mixed);
+        assert_eq!(Precipitation::detect(616, None, None), Precipitation::Mixed);
+
+        // rain with snow reported, or snow with rain, is mixed
+        assert_eq!(Precipitation::detect(500, None, Some(0.2)), Precipitation::Mixed);
+        assert_eq!(Precipitation::detect(600, Some(0.2), None), Precipitation::Mixed);
+    }
+
+    #[test]
+    fn detects_precipitation_from_volumes() {
+        assert_eq!(Precipitation::detect(800, None, None), Precipitation::None);
+        assert_eq!(Precipitation::detect(804, Some(0.3), None), Precipitation::Rain);
+        assert_eq!(Precipitation::detect(741, None, Some(0.3)), Precipitation::Snow);
+        assert_eq!(Precipitation::detect(804, Some(0.3), Some(0.3)), Precipitation::Mixed);
+    }
+
+    #[test]
+    fn parses_snow_volumes() {
+        let forecast = WeatherList::parse(JSON_RESPONSE_SNOW_MICRO).unwrap()
+            .transform(Clock::City, &HourFilter::from(Preset::All))
+            .unwrap();
+        let sections: Vec<&WeatherSection> = forecast.sections().map(|(_, s)| s).collect();
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].precip_type, Precipitation::Snow);
+        assert_eq!((sections[0].rain_volume, sections[0].snow_volume), (None, Some(2.81)));
+        assert_eq!(sections[0].precipitation, 2.81);
+        assert_eq!(sections[1].precip_type, Precipitation::Mixed);
+        assert_eq!((sections[1].rain_volume, sections[1].snow_volume), (Some(0.41), Some(0.93)));
+        assert!((sections[1].precipitation - 1.34).abs() < 1e-5);
+    }
+
+    #[test]
+    fn transforms_current_weather() {
+        let weather = CurrentWeather::parse(JSON_CURRENT_RESPONSE).unwrap();
+        let current = weather.transform(Clock::City).unwrap();
+
+        match weather.location() {
+            Location::Coordinates(lat, lon) => assert!((lat - 32.2217).abs() < 1e-4 && (lon + 110.9265).abs() < 1e-4),
+            other => panic!("expected coordinates, got {:?}", other),
+        }
+        assert_eq!(weather.city().timezone(), -25200);
+        assert_eq!(current.place, "Tucson US");
+        assert_eq!(current.datetime, "Sat 06-18 13:12");
+        assert_eq!(current.conditions.iter().map(|c| c.description.as_str()).collect::<Vec<_>>(), ["light rain", "mist"]);
+        assert_eq!((current.rain_one_hour, current.snow_one_hour), (Some(0.31), None));
+        assert_eq!(current.wind_gust, Some(26.46));
+        assert_eq!(weather.transform(Clock::Utc).unwrap().datetime, "Sat 06-18 20:12");
+    }
+
+    // Hourly section at the given hour of 2022-06-19 UTC with a rain amount
+    fn hour(hour: u64, rain: f32, span: Span) -> (DayKey, u8, WeatherSection, Span) {
+        let (day, mut section) = WeatherSection::at(&Clock::Utc, 1655596800 + hour * 3600, 0);
+
+        section.temp = hour as f32;
+        section.temp_min = hour as f32;
+        section.temp_max = hour as f32;
+        section.rain_volume = Some(rain).filter(|r| *r > 0.0);
+        section.precipitation = rain;
+        section.accumulation = Some(span.1 - span.0);
+
+        (day, section.hour, section, span)
+    }
+
+    #[test]
+    fn rolls_up_three_hours_of_amounts() {
+        // amounts for the hour ahead of each section, as met.no gives them
+        let list = (0..7).map(|h| hour(h, 0.5 * h as f32, (1655596800 + h * 3600, 1655596800 + (h + 1) * 3600))).collect();
+        let rolled = three_hourly(list);
+        let hours: Vec<u8> = rolled.iter().map(|(_, h, _)| *h).collect();
+
+        assert_eq!(hours, [0, 3, 6]);
+        // 03:00 holds the hours from midnight, 0 + 0.5 + 1.0
+        assert_eq!(rolled[1].2.precipitation, 1.5);
+        assert_eq!(rolled[1].2.rain_volume, Some(1.5));
+        assert_eq!(rolled[1].2.precip_type, Precipitation::Rain);
+        assert_eq!((rolled[1].2.temp_min, rolled[1].2.temp_max), (1.0, 3.0));
+        assert_eq!(rolled[2].2.precipitation, 1.5 + 2.0 + 2.5);
+    }
+
+    #[test]
+    fn rolls_up_longer_amounts_whole() {
+        // 6 hour amounts ahead of each step, as in the later met.no steps
+        let start = 1655596800;
+        let list = (0..3).map(|i| hour(6 * i, 1.2 / (i + 1) as f32, (start + 6 * i * 3600, start + 6 * (i + 1) * 3600))).collect();
+        let rolled = three_hourly(list);
+        let amounts: Vec<f32> = rolled.iter().map(|(_, _, s)| s.precipitation).collect();
+
+        // each section holds the 6 hours since the previous one, so the
+        // amounts up to the last section add up to what was forecast
+        assert_eq!(rolled.iter().map(|(_, h, _)| *h).collect::<Vec<_>>(), [0, 6, 12]);
+        assert_eq!(amounts, [0.0, 1.2, 0.6]);
+        assert_eq!(amounts.iter().sum::<f32>(), 1.2 + 0.6);
+        assert_eq!(rolled[1].2.accumulation, Some(6 * 3600));
+    }
+
+    #[test]
+    fn groups_days_on_utc_clock() {
+        let days = grouped(Clock::Utc);
+
+        assert_eq!(days.first().unwrap(), &(String::from("2022-06-18"), vec![21]));
+        assert_eq!(days.last().unwrap(), &(String::from("2022-06-23"), vec![0, 3, 6, 9, 12, 15, 18]));
+        assert_eq!(days.iter().map(|(_, h)| h.len()).sum::<usize>(), 40);
+    }
+
+    #[test]
+    fn works_out_sunrise_and_sunset() {
+        let date = NaiveDate::from_ymd(2022, 6, 18);
+        let (sunrise, sunset) = solar_daylight(32.2217, -110.9265, date).unwrap();
+
+        // within two minutes of OpenWeatherMap's figures for Tucson
+        assert!((sunrise as i64 - 1655554625).abs() < 120, "{}", sunrise);
+        assert!((sunset as i64 - 1655605961).abs() < 120, "{}", sunset);
+
+        // midnight sun and polar night in Tromsø
+        assert_eq!(solar_daylight(69.65, 18.96, date), None);
+        assert_eq!(solar_daylight(69.65, 18.96, NaiveDate::from_ymd(2022, 12, 18)), None);
+    }
 }
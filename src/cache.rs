@@ -0,0 +1,214 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::request::ApiError;
+
+// Module keeps web service responses on disk under the user cache dir
+// (e.g. ~/.cache/weathr/responses), so repeat runs within a response's
+// time to live skip the network. Offline mode serves whatever was last
+// stored however old, and refresh mode always refetches.
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum CacheMode {
+    #[default]
+    Normal,
+    Refresh,
+    Offline,
+}
+
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+    dir: Option<PathBuf>,
+    ttl: Option<u64>,
+    mode: CacheMode,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self { ResponseCache::new() }
+}
+
+impl ResponseCache {
+    pub fn new() -> ResponseCache {
+        ResponseCache {
+            dir: dirs::cache_dir().map(|d| d.join("weathr").join("responses")),
+            ttl: None,
+            mode: CacheMode::default(),
+        }
+    }
+
+    // Use a different cache directory, None disables caching
+    pub fn with_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.dir = dir;
+        self
+    }
+
+    // Time to live in seconds for every response, overriding each endpoint's own
+    pub fn with_ttl(mut self, ttl: Option<u64>) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: CacheMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[inline]
+    pub fn mode(&self) -> CacheMode {
+        self.mode
+    }
+
+    // Parsed cached body while younger than its ttl, otherwise fetched and
+    // parsed, then stored only when it parses so a bad body is never reused.
+    // Offline bodies of any age are served along with when they were fetched
+    pub(crate) fn fetch<T, F, P>(&self, key: &str, ttl: u64, fetch: F, parse: P) -> Result<(T, Option<u64>), ApiError>
+    where F: FnOnce() -> Result<String, ApiError>,
+          P: Fn(&str) -> Result<T, ApiError>
+    {
+        let path = self.dir.as_ref().map(|dir| dir.join(format!("{}.json", sanitize(key))));
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+        let cached: Option<Cached> = path.as_ref()
+            .and_then(|p| fs::read_to_string(p).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+
+        match (self.mode, cached) {
+            (CacheMode::Offline, Some(c)) => return Ok((parse(&c.body)?, Some(c.fetched))),
+            (CacheMode::Offline, None) => return Err(ApiError::NotCached(key.to_owned())),
+            (CacheMode::Normal, Some(c)) if now < c.fetched + self.ttl.unwrap_or(ttl) => return Ok((parse(&c.body)?, None)),
+            _ => (),
+        }
+
+        let body = fetch()?;
+        let value = parse(&body)?;
+
+        // caching is best effort, failures to write are ignored
+        if let Some(path) = path {
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+
+            if let Ok(contents) = serde_json::to_string(&Cached { fetched: now, body }) {
+                let _ = fs::write(path, contents);
+            }
+        }
+
+        Ok((value, None))
+    }
+}
+
+// Response body with the unix time it was fetched
+#[derive(Deserialize, Serialize, Debug)]
+struct Cached {
+    fetched: u64,
+    body: String,
+}
+
+// Keys become file names, anything but alphanumerics, '.' and '-' is replaced
+fn sanitize(key: &str) -> String {
+    key.chars().map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::env;
+
+    use super::*;
+
+    // Cache in a fresh directory unique to the test
+    fn cache(name: &str, mode: CacheMode) -> ResponseCache {
+        let dir = env::temp_dir().join(format!("weathr-cache-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+
+        ResponseCache::new().with_dir(Some(dir)).with_mode(mode)
+    }
+
+    // Fetches counting the calls made, each answering with its call number
+    fn fetch(cache: &ResponseCache, ttl: u64, calls: &Cell<u32>) -> Result<(String, Option<u64>), ApiError> {
+        cache.fetch("weather?q=Tucson", ttl, || {
+            calls.set(calls.get() + 1);
+            Ok(format!("body {}", calls.get()))
+        }, |body| Ok(body.to_owned()))
+    }
+
+    #[test]
+    fn serves_a_body_within_its_ttl() {
+        let cache = cache("ttl", CacheMode::Normal);
+        let calls = Cell::new(0);
+
+        assert_eq!(fetch(&cache, 600, &calls).unwrap(), (String::from("body 1"), None));
+        assert_eq!(fetch(&cache, 600, &calls).unwrap(), (String::from("body 1"), None));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn refetches_an_expired_body() {
+        let cache = cache("expired", CacheMode::Normal);
+        let calls = Cell::new(0);
+
+        fetch(&cache, 0, &calls).unwrap();
+        assert_eq!(fetch(&cache, 0, &calls).unwrap().0, "body 2");
+
+        // the override wins over the endpoint's ttl
+        let cache = cache.with_ttl(Some(0));
+        assert_eq!(fetch(&cache, 600, &calls).unwrap().0, "body 3");
+    }
+
+    #[test]
+    fn refresh_always_refetches_and_stores() {
+        let cache = cache("refresh", CacheMode::Refresh);
+        let calls = Cell::new(0);
+
+        fetch(&cache, 600, &calls).unwrap();
+        assert_eq!(fetch(&cache, 600, &calls).unwrap().0, "body 2");
+
+        let normal = cache.with_mode(CacheMode::Normal);
+        assert_eq!(fetch(&normal, 600, &calls).unwrap().0, "body 2");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn offline_serves_any_age_with_when_it_was_fetched() {
+        let cache = cache("offline-hit", CacheMode::Normal);
+        let calls = Cell::new(0);
+        let before = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+        fetch(&cache, 0, &calls).unwrap();
+        let (body, fetched) = fetch(&cache.with_mode(CacheMode::Offline), 0, &calls).unwrap();
+
+        assert_eq!((body.as_str(), calls.get()), ("body 1", 1));
+        assert!(fetched.is_some_and(|f| f >= before));
+    }
+
+    #[test]
+    fn never_stores_a_body_that_fails_to_parse() {
+        let cache = cache("unparsed", CacheMode::Normal);
+        let calls = Cell::new(0);
+        let first = cache.fetch("weather?q=Tucson", 600, || {
+            calls.set(calls.get() + 1);
+            Ok(String::from("{\"cod\":429}"))
+        }, |_| Err::<String, _>(ApiError::BadParse("error body")));
+
+        assert!(matches!(first, Err(ApiError::BadParse("error body"))));
+
+        // nothing was kept, so the next call goes back to the network
+        assert_eq!(fetch(&cache, 600, &calls).unwrap().0, "body 2");
+        assert_eq!(fetch(&cache, 600, &calls).unwrap().0, "body 2");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn offline_miss_is_not_cached() {
+        let cache = cache("offline-miss", CacheMode::Offline);
+        let calls = Cell::new(0);
+
+        match fetch(&cache, 600, &calls) {
+            Err(ApiError::NotCached(key)) => assert_eq!(key, "weather?q=Tucson"),
+            other => panic!("expected not cached, got {:?}", other),
+        }
+        assert_eq!(calls.get(), 0);
+    }
+}
@@ -6,14 +6,65 @@ use clap::{App, Arg};
 pub struct WeatherConfig {
     location: String,
     units: Option<String>,
+    tz: Option<String>,
+    hours: Option<String>,
+    include_hours: Option<String>,
+    exclude_hours: Option<String>,
+    daylight: bool,
+    columns: Option<String>,
+    pick: Option<usize>,
+    now: bool,
+    air: bool,
+    granularity: Option<String>,
+    alerts: bool,
+    provider: Option<String>,
+    ensemble: Option<String>,
+    fallback: Option<String>,
+    offline: bool,
+    refresh: bool,
+    cache_ttl: Option<u64>,
+    connect_timeout: Option<u64>,
+    timeout: Option<u64>,
+    retries: Option<u32>,
+    output: Option<String>,
+    out_file: Option<String>,
     debug: bool,
 }
 
 impl WeatherConfig {
-    fn new(location: String, units: Option<String>, debug: bool) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    fn new(location: String, units: Option<String>, tz: Option<String>, hours: Option<String>,
+           include_hours: Option<String>, exclude_hours: Option<String>, daylight: bool,
+           columns: Option<String>, pick: Option<usize>, now: bool, air: bool,
+           granularity: Option<String>, alerts: bool, provider: Option<String>,
+           ensemble: Option<String>, fallback: Option<String>, offline: bool, refresh: bool,
+           cache_ttl: Option<u64>, connect_timeout: Option<u64>, timeout: Option<u64>,
+           retries: Option<u32>, output: Option<String>, out_file: Option<String>, debug: bool) -> Self {
         WeatherConfig {
             location,
             units,
+            tz,
+            hours,
+            include_hours,
+            exclude_hours,
+            daylight,
+            columns,
+            pick,
+            now,
+            air,
+            granularity,
+            alerts,
+            provider,
+            ensemble,
+            fallback,
+            offline,
+            refresh,
+            cache_ttl,
+            connect_timeout,
+            timeout,
+            retries,
+            output,
+            out_file,
             debug,
         }
     }
@@ -33,20 +84,142 @@ impl WeatherConfig {
         self.units.as_ref().map(|s| s.as_ref())
     }
 
+    #[inline]
+    pub fn tz(&self) -> Option<&str> {
+        self.tz.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn hours(&self) -> Option<&str> {
+        self.hours.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn include_hours(&self) -> Option<&str> {
+        self.include_hours.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn exclude_hours(&self) -> Option<&str> {
+        self.exclude_hours.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn daylight(&self) -> bool {
+        self.daylight
+    }
+
+    #[inline]
+    pub fn columns(&self) -> Option<&str> {
+        self.columns.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn pick(&self) -> Option<usize> {
+        self.pick
+    }
+
+    #[inline]
+    pub fn now(&self) -> bool {
+        self.now
+    }
+
+    #[inline]
+    pub fn air(&self) -> bool {
+        self.air
+    }
+
+    #[inline]
+    pub fn granularity(&self) -> Option<&str> {
+        self.granularity.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn alerts(&self) -> bool {
+        self.alerts
+    }
+
+    #[inline]
+    pub fn provider(&self) -> Option<&str> {
+        self.provider.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn ensemble(&self) -> Option<&str> {
+        self.ensemble.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn fallback(&self) -> Option<&str> {
+        self.fallback.as_ref().map(|s| s.as_ref())
+    }
+
+    #[inline]
+    pub fn offline(&self) -> bool {
+        self.offline
+    }
+
+    #[inline]
+    pub fn refresh(&self) -> bool {
+        self.refresh
+    }
+
+    // Minutes cached responses are reused for
+    #[inline]
+    pub fn cache_ttl(&self) -> Option<u64> {
+        self.cache_ttl
+    }
+
+    // Seconds allowed to establish a connection
+    #[inline]
+    pub fn connect_timeout(&self) -> Option<u64> {
+        self.connect_timeout
+    }
+
+    // Seconds allowed for each read once connected
+    #[inline]
+    pub fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+
+    // Retries after transient request failures
+    #[inline]
+    pub fn retries(&self) -> Option<u32> {
+        self.retries
+    }
+
+    #[inline]
+    pub fn output(&self) -> Option<&str> {
+        self.output.as_deref()
+    }
+
+    // File written instead of stdout
+    #[inline]
+    pub fn out_file(&self) -> Option<&str> {
+        self.out_file.as_deref()
+    }
+
     // Match the params expected with the params actually given using clap
     pub fn load() -> WeatherConfig {
         let matches = App::new("weathr")
             .version("0.1.0")
             .author("Bibek Pandey")
-            .about("Simple CLI Weather App using OpenWeatherMap")
+            .about("Simple CLI Weather App using OpenWeatherMap and other providers")
             .arg(
                 Arg::with_name("location")
                     .takes_value(true)
                     .required(true)
-                    .help("City Location")
+                    .help("Location: city[,state][,country], zip[,country], lat,lon or id:<city id>")
                     .short('l')
                     .long("location"),
             )
+            .arg(
+                Arg::with_name("air")
+                    .takes_value(false)
+                    .help("Show air quality (same as --columns air)")
+                    .short('a')
+                    .long("air"),
+            )
             .arg(
                 Arg::with_name("debug")
                     .takes_value(false)
@@ -57,25 +230,198 @@ impl WeatherConfig {
             .arg(
                 Arg::with_name("units")
                     .takes_value(true)
-                    .possible_values(&["imperial", "metric"])
+                    .possible_values(["imperial", "metric"])
                     .help("Specify units type")
                     .short('u')
                     .long("units"),
             )
+            .arg(
+                Arg::with_name("provider")
+                    .takes_value(true)
+                    .possible_values(["openweathermap", "owm", "open-meteo", "openmeteo", "nws", "met.no", "metno"])
+                    .help("Weather service to query (default openweathermap, open-meteo without an api key)")
+                    .short('P')
+                    .long("provider"),
+            )
+            .arg(
+                Arg::with_name("ensemble")
+                    .takes_value(true)
+                    .value_name("provider,provider,..")
+                    .conflicts_with("provider")
+                    .help("Combine several providers, showing their mean and spread")
+                    .short('E')
+                    .long("ensemble"),
+            )
+            .arg(
+                Arg::with_name("fallback")
+                    .takes_value(true)
+                    .value_name("provider,provider,..|none")
+                    .help("Providers tried in turn when the provider is down (default open-meteo)")
+                    .short('F')
+                    .long("fallback"),
+            )
+            .arg(
+                Arg::with_name("tz")
+                    .takes_value(true)
+                    .value_name("utc|local|city|IANA name")
+                    .help("Clock used to group and show forecast times (default city)")
+                    .short('t')
+                    .long("tz"),
+            )
+            .arg(
+                Arg::with_name("granularity")
+                    .takes_value(true)
+                    .possible_values(["minutely", "hourly", "3h", "daily"])
+                    .help("Forecast time resolution, all but 3h use One Call 3.0 (default 3h)")
+                    .short('g')
+                    .long("granularity"),
+            )
+            .arg(
+                Arg::with_name("hours")
+                    .takes_value(true)
+                    .possible_values(["all", "daytime", "waking", "night"])
+                    .help("Hours preset to show (default waking)")
+                    .short('H')
+                    .long("hours"),
+            )
+            .arg(
+                Arg::with_name("include-hours")
+                    .takes_value(true)
+                    .value_name("0,3,21")
+                    .help("Only show these hours, overrides preset")
+                    .long("include-hours"),
+            )
+            .arg(
+                Arg::with_name("exclude-hours")
+                    .takes_value(true)
+                    .value_name("0,3,21")
+                    .help("Never show these hours")
+                    .long("exclude-hours"),
+            )
+            .arg(
+                Arg::with_name("daylight")
+                    .takes_value(false)
+                    .help("Only show hours between sunrise and sunset")
+                    .long("daylight"),
+            )
+            .arg(
+                Arg::with_name("no-alerts")
+                    .takes_value(false)
+                    .help("Skip weather alerts and the requests for them")
+                    .long("no-alerts"),
+            )
+            .arg(
+                Arg::with_name("columns")
+                    .takes_value(true)
+                    .value_name("range,pressure,sky,gust,meta,air,spread|all")
+                    .help("Extra column groups to display")
+                    .short('c')
+                    .long("columns"),
+            )
+            .arg(
+                Arg::with_name("pick")
+                    .takes_value(true)
+                    .value_name("N")
+                    .validator(|s| s.parse::<usize>())
+                    .help("Choose the Nth matching place instead of prompting")
+                    .short('p')
+                    .long("pick"),
+            )
+            .arg(
+                Arg::with_name("now")
+                    .takes_value(false)
+                    .help("Only show current conditions")
+                    .short('n')
+                    .long("now"),
+            )
+            .arg(
+                Arg::with_name("offline")
+                    .takes_value(false)
+                    .conflicts_with("refresh")
+                    .help("Show the last cached forecast without going online")
+                    .long("offline"),
+            )
+            .arg(
+                Arg::with_name("refresh")
+                    .takes_value(false)
+                    .help("Ignore cached responses and fetch fresh ones")
+                    .long("refresh"),
+            )
+            .arg(
+                Arg::with_name("cache-ttl")
+                    .takes_value(true)
+                    .value_name("minutes")
+                    .validator(|s| s.parse::<u64>())
+                    .help("Reuse cached responses for this long (default 10 to 180 by endpoint)")
+                    .long("cache-ttl"),
+            )
+            .arg(
+                Arg::with_name("connect-timeout")
+                    .takes_value(true)
+                    .value_name("seconds")
+                    .validator(|s| s.parse::<u64>())
+                    .help("Give up connecting to a web service after this long (default 5)")
+                    .long("connect-timeout"),
+            )
+            .arg(
+                Arg::with_name("timeout")
+                    .takes_value(true)
+                    .value_name("seconds")
+                    .validator(|s| s.parse::<u64>())
+                    .help("Give up waiting on a stalled response after this long (default 15)")
+                    .long("timeout"),
+            )
+            .arg(
+                Arg::with_name("retries")
+                    .takes_value(true)
+                    .value_name("count")
+                    .validator(|s| s.parse::<u32>())
+                    .help("Retry failed requests with backoff this many times (default 3)")
+                    .long("retries"),
+            )
             .arg(
                 Arg::with_name("output")
                     .takes_value(true)
-                    .possible_values(&["tabular"])
-                    .help("Display output type")
+                    .possible_values(["tabular", "json", "csv", "tsv", "markdown", "md", "html", "ics"])
+                    .help("Display output type (default tabular)")
                     .short('o')
                     .long("output"),
             )
+            .arg(
+                Arg::with_name("out-file")
+                    .takes_value(true)
+                    .value_name("path")
+                    .help("Write the output to this file instead of the terminal")
+                    .long("out-file"),
+            )
             .get_matches();
 
         // Unpack the args that have matched successfully and capture in Config
         WeatherConfig::new(
             matches.value_of("location").unwrap().to_string(),
             matches.value_of("units").map(|s| s.to_string()),
+            matches.value_of("tz").map(|s| s.to_string()),
+            matches.value_of("hours").map(|s| s.to_string()),
+            matches.value_of("include-hours").map(|s| s.to_string()),
+            matches.value_of("exclude-hours").map(|s| s.to_string()),
+            matches.is_present("daylight"),
+            matches.value_of("columns").map(|s| s.to_string()),
+            matches.value_of("pick").and_then(|s| s.parse().ok()),
+            matches.is_present("now"),
+            matches.is_present("air"),
+            matches.value_of("granularity").map(|s| s.to_string()),
+            !matches.is_present("no-alerts"),
+            matches.value_of("provider").map(|s| s.to_string()),
+            matches.value_of("ensemble").map(|s| s.to_string()),
+            matches.value_of("fallback").map(|s| s.to_string()),
+            matches.is_present("offline"),
+            matches.is_present("refresh"),
+            matches.value_of("cache-ttl").and_then(|s| s.parse().ok()),
+            matches.value_of("connect-timeout").and_then(|s| s.parse().ok()),
+            matches.value_of("timeout").and_then(|s| s.parse().ok()),
+            matches.value_of("retries").and_then(|s| s.parse().ok()),
+            matches.value_of("output").map(str::to_owned),
+            matches.value_of("out-file").map(str::to_owned),
             matches.is_present("debug"),
         )
     }
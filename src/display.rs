@@ -1,65 +1,580 @@
 use std::fmt;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
 
-use colored::Colorize;
+use chrono::{DateTime, FixedOffset, SecondsFormat, Timelike};
+use colored::{ColoredString, Colorize};
+use serde::{Serialize, Serializer};
 use tabular::{Table, Row};
 
-use crate::backend::{WeatherList, DayKey, City};
+use crate::backend::{datetime, rfc3339, WeatherList, DayKey, City, Clock, Condition, Granularity, Precipitation};
+use crate::filter::HourFilter;
+use crate::request::{ApiError, Units};
 
 
 /*
 Flattened Structs and partially normalized
  */
 
+// Serialized with typed times only, the display strings are skipped
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub(crate) struct WeatherSection {
-    day_of_week: String,
-    hour: u8,
-    datetime: String,
-    temp: f32,
-    feels_like: f32, 
-    humidity: u16,
-    description: String,
-    wind_speed: f32,
-    wind_deg: u16,
-    rain_three_hour: Option<f32>,
-}
-
-// Final resultant top-level Weather struct to be used for output display
-#[derive(Debug)]
+    pub(crate) timestamp: u64,
+    // start time on the forecast's clock
+    #[serde(rename = "time", serialize_with = "local_time")]
+    pub(crate) local: DateTime<FixedOffset>,
+    #[serde(skip)]
+    pub(crate) day_of_week: String,
+    #[serde(skip)]
+    pub(crate) hour: u8,
+    #[serde(skip)]
+    pub(crate) datetime: String,
+    #[serde(skip)]
+    pub(crate) dt_txt: String,
+    pub(crate) temp: f32,
+    pub(crate) feels_like: f32,
+    pub(crate) temp_min: f32,
+    pub(crate) temp_max: f32,
+    pub(crate) pressure: u16,
+    pub(crate) sea_level: Option<u16>,
+    pub(crate) grnd_level: Option<u16>,
+    pub(crate) humidity: u16,
+    pub(crate) conditions: Vec<Condition>,
+    pub(crate) clouds: u8,
+    pub(crate) visibility: Option<u32>,
+    pub(crate) pop: f32,
+    pub(crate) wind_speed: f32,
+    pub(crate) wind_deg: u16,
+    pub(crate) wind_gust: Option<f32>,
+    // volumes over the section's period, 3h, 1h or a whole day
+    pub(crate) rain_volume: Option<f32>,
+    pub(crate) snow_volume: Option<f32>,
+    pub(crate) precipitation: f32,
+    // seconds the amounts accumulated over, None when the provider gives none
+    #[serde(skip)]
+    pub(crate) accumulation: Option<u64>,
+    pub(crate) precip_type: Precipitation,
+    pub(crate) daytime: bool,
+    pub(crate) air_quality: Option<AirQuality>,
+    // set when the section is an ensemble mean
+    pub(crate) spread: Option<Spread>,
+}
+
+// Flattened current conditions, shown as a compact block
+#[derive(Debug, Clone)]
+pub struct CurrentConditions {
+    pub(crate) place: String,
+    pub(crate) datetime: String,
+    pub(crate) temp: f32,
+    pub(crate) feels_like: f32,
+    pub(crate) temp_min: f32,
+    pub(crate) temp_max: f32,
+    pub(crate) pressure: u16,
+    pub(crate) humidity: u16,
+    pub(crate) conditions: Vec<Condition>,
+    pub(crate) clouds: u8,
+    pub(crate) visibility: Option<u32>,
+    pub(crate) wind_speed: f32,
+    pub(crate) wind_deg: u16,
+    pub(crate) wind_gust: Option<f32>,
+    pub(crate) rain_one_hour: Option<f32>,
+    pub(crate) snow_one_hour: Option<f32>,
+    pub(crate) air_quality: Option<AirQuality>,
+}
+
+// Air quality index and pollutant concentrations (μg/m3)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AirQuality {
+    pub(crate) aqi: u8,
+    pub(crate) pm2_5: f32,
+    pub(crate) pm10: f32,
+    pub(crate) o3: f32,
+    pub(crate) no2: f32,
+    pub(crate) so2: f32,
+    pub(crate) co: f32,
+}
+
+impl AirQuality {
+    // OpenWeatherMap qualitative band for the index
+    pub fn band(&self) -> &'static str {
+        match self.aqi {
+            1 => "good",
+            2 => "fair",
+            3 => "moderate",
+            4 => "poor",
+            _ => "very poor",
+        }
+    }
+
+    // Index with its band e.g. "4 poor"
+    fn label(&self) -> String {
+        format!("{} {}", self.aqi, self.band())
+    }
+}
+
+// How closely ensemble members agree on a value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+}
+
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::High => write!(f, "high"),
+            Self::Medium => write!(f, "med"),
+            Self::Low => write!(f, "low"),
+        }
+    }
+}
+
+// Style of each piece of output, shown as terminal colors by the table
+// and mirrored as css colors by the html renderer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Tone {
+    Plain,
+    Location,
+    Stale,
+    Heading,
+    Now,
+    Italic,
+    Day,
+    Temp,
+    Feels,
+    FeelsRange,
+    Humidity,
+    Wind,
+    Rain,
+    Snow,
+    Precip,
+    Pressure,
+    Sky,
+    Desc,
+    // air quality index, green good through magenta very poor
+    Air(u8),
+    Confidence(Confidence),
+    AlertTitle,
+    AlertPeriod,
+    AlertText,
+}
+
+impl Tone {
+    pub(crate) fn paint(&self, text: &str) -> ColoredString {
+        match self {
+            Self::Plain => text.normal(),
+            Self::Location => text.cyan().bold(),
+            Self::Stale => text.yellow().bold(),
+            Self::Heading => text.italic().purple(),
+            Self::Now => text.bold().purple(),
+            Self::Italic => text.italic(),
+            Self::Day => text.bold(),
+            Self::Temp => text.yellow(),
+            Self::Feels => text.bright_yellow().bold(),
+            Self::FeelsRange => text.bright_yellow(),
+            Self::Humidity => text.green(),
+            Self::Wind => text.blue().on_bright_blue(),
+            Self::Rain => text.blue().italic().on_bright_white(),
+            Self::Snow => text.white().italic().on_bright_blue(),
+            Self::Precip => text.blue(),
+            Self::Pressure => text.magenta(),
+            Self::Sky => text.white(),
+            Self::Desc => text.bright_green().bold().italic(),
+            Self::Air(1) => text.green(),
+            Self::Air(2) => text.bright_green(),
+            Self::Air(3) => text.yellow(),
+            Self::Air(4) => text.red().bold(),
+            Self::Air(_) => text.magenta().bold(),
+            Self::Confidence(Confidence::High) => text.green(),
+            Self::Confidence(Confidence::Medium) => text.yellow(),
+            Self::Confidence(Confidence::Low) => text.red().bold(),
+            Self::AlertTitle => text.white().bold().on_red(),
+            Self::AlertPeriod => text.red().bold(),
+            Self::AlertText => text.red(),
+        }
+    }
+
+    // Inline css matching paint, in the colors of a typical dark terminal
+    pub(crate) fn css(&self) -> &'static str {
+        match self {
+            Self::Plain => "",
+            Self::Location => "color:#11a8cd;font-weight:bold",
+            Self::Stale => "color:#e5e510;font-weight:bold",
+            Self::Heading => "color:#bc3fbc;font-style:italic",
+            Self::Now => "color:#bc3fbc;font-weight:bold",
+            Self::Italic => "font-style:italic",
+            Self::Day => "font-weight:bold",
+            Self::Temp => "color:#e5e510",
+            Self::Feels => "color:#f5f543;font-weight:bold",
+            Self::FeelsRange => "color:#f5f543",
+            Self::Humidity => "color:#0dbc79",
+            Self::Wind => "color:#2472c8;background:#3b8eea",
+            Self::Rain => "color:#2472c8;font-style:italic;background:#ffffff",
+            Self::Snow => "color:#e5e5e5;font-style:italic;background:#3b8eea",
+            Self::Precip => "color:#2472c8",
+            Self::Pressure => "color:#bc3fbc",
+            Self::Sky => "color:#e5e5e5",
+            Self::Desc => "color:#23d18b;font-weight:bold;font-style:italic",
+            Self::Air(1) => "color:#0dbc79",
+            Self::Air(2) => "color:#23d18b",
+            Self::Air(3) => "color:#e5e510",
+            Self::Air(4) => "color:#cd3131;font-weight:bold",
+            Self::Air(_) => "color:#bc3fbc;font-weight:bold",
+            Self::Confidence(Confidence::High) => "color:#0dbc79",
+            Self::Confidence(Confidence::Medium) => "color:#e5e510",
+            Self::Confidence(Confidence::Low) => "color:#cd3131;font-weight:bold",
+            Self::AlertTitle => "color:#e5e5e5;font-weight:bold;background:#cd3131",
+            Self::AlertPeriod => "color:#cd3131;font-weight:bold",
+            Self::AlertText => "color:#cd3131",
+        }
+    }
+}
+
+// Paints text as terminal colors
+#[inline]
+fn ansi(text: &str, tone: Tone) -> String {
+    tone.paint(text).to_string()
+}
+
+// Range of member values behind an ensemble mean
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Spread {
+    pub(crate) members: usize,
+    pub(crate) temp: (f32, f32),
+    pub(crate) temp_confidence: Confidence,
+    pub(crate) precipitation: (f32, f32),
+    pub(crate) precip_confidence: Confidence,
+}
+
+// Government weather alert issued for the location
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub(crate) sender: String,
+    pub(crate) event: String,
+    #[serde(serialize_with = "rfc3339")]
+    pub(crate) start: u64,
+    #[serde(serialize_with = "rfc3339")]
+    pub(crate) end: u64,
+    #[serde(skip)]
+    pub(crate) starts: String,
+    #[serde(skip)]
+    pub(crate) ends: String,
+    pub(crate) description: String,
+    pub(crate) tags: Vec<String>,
+}
+
+impl Alert {
+    // Whether the alert is in effect at the given unix time
+    #[inline]
+    pub fn is_active(&self, now: u64) -> bool {
+        self.start <= now && now < self.end
+    }
+
+    // Red banner with the event, issuer, period then description
+    fn banner<P: Fn(&str, Tone) -> String>(&self, paint: P) -> String {
+        let title = paint(&format!(" ⚠ {} ", self.event.to_uppercase()), Tone::AlertTitle);
+        let period = paint(&format!("{} - {}  {}", self.starts, self.ends, self.sender), Tone::AlertPeriod);
+        let description = self.description.lines()
+            .map(|line| format!("  {}", paint(line, Tone::AlertText)))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!("{}  {}\n{}", title, period, description)
+    }
+}
+
+// Final resultant top-level Weather struct to be used for output display,
+// serializes as the normalized forecast minus display only settings
+#[derive(Debug, Serialize)]
 pub struct WeatherForecast {
     location: City,
+    #[serde(skip)]
+    clock: Clock,
+    #[serde(skip)]
+    columns: Columns,
+    #[serde(skip)]
+    current: Option<CurrentConditions>,
+    granularity: Granularity,
+    units: Units,
+    alerts: Vec<Alert>,
+    #[serde(serialize_with = "stale_since")]
+    stale_since: Option<u64>,
+    #[serde(serialize_with = "days")]
     days: BTreeMap<DayKey, Vec<WeatherSection>>,
 }
 
 
 impl WeatherForecast {
-    pub(crate) fn new(location: City, days: BTreeMap<DayKey, Vec<WeatherSection>>) -> Self {
+    pub(crate) fn new(location: City, clock: Clock, days: BTreeMap<DayKey, Vec<WeatherSection>>) -> Self {
         WeatherForecast {
             location,
+            clock,
+            columns: Columns::default(),
+            current: None,
+            granularity: Granularity::default(),
+            units: Units::default(),
+            alerts: Vec::new(),
+            stale_since: None,
             days
         }
     }
+
+    pub fn with_alerts(mut self, alerts: Vec<Alert>) -> Self {
+        self.alerts = alerts;
+        self
+    }
+
+    #[inline]
+    pub fn alerts(&self) -> &[Alert] {
+        &self.alerts
+    }
+
+    // Whether any alert is in effect at the given unix time
+    pub fn has_active_alert(&self, now: u64) -> bool {
+        self.alerts.iter().any(|a| a.is_active(now))
+    }
+
+    // Time resolution of the sections, changes the table layout
+    pub fn with_granularity(mut self, granularity: Granularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    // Units the values were fetched in, carried into serialized output
+    pub fn with_units(mut self, units: Units) -> Self {
+        self.units = units;
+        self
+    }
+
+    // Show a right now block above the daily tables
+    pub fn with_current(mut self, current: CurrentConditions) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    // Merge air quality readings into sections by timestamp
+    pub fn with_air_quality(mut self, readings: &BTreeMap<u64, AirQuality>) -> Self {
+        for section in self.days.values_mut().flatten() {
+            section.air_quality = readings.get(&section.timestamp).copied();
+        }
+
+        self
+    }
+
+    // Cached data served offline, fetched at the given unix time
+    pub fn with_stale_since(mut self, fetched: Option<u64>) -> Self {
+        self.stale_since = fetched;
+        self
+    }
+
+    // Note the provider that served the forecast in the location header
+    pub fn with_source(mut self, source: &'static str) -> Self {
+        self.location = self.location.with_source(source);
+        self
+    }
+
+    // Rename the location header's place
+    pub(crate) fn with_place(mut self, name: &str, country: &str) -> Self {
+        self.location = self.location.with_place(name, country);
+        self
+    }
+
+    // Select which optional column groups are displayed
+    pub fn with_columns(mut self, columns: Columns) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    #[inline]
+    pub fn location(&self) -> &City {
+        &self.location
+    }
+
+    #[inline]
+    pub(crate) fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    #[inline]
+    pub(crate) fn units(&self) -> Units {
+        self.units
+    }
+
+    pub(crate) fn days(&self) -> impl Iterator<Item = (&DayKey, &[WeatherSection])> {
+        self.days.iter().map(|(k, v)| (k, v.as_slice()))
+    }
+
+    pub(crate) fn sections(&self) -> impl Iterator<Item = (&DayKey, &WeatherSection)> {
+        self.days.iter().flat_map(|(k, v)| v.iter().map(move |s| (k, s)))
+    }
+
+    pub(crate) fn sections_mut(&mut self) -> impl Iterator<Item = (&DayKey, &mut WeatherSection)> {
+        self.days.iter_mut().flat_map(|(k, v)| v.iter_mut().map(move |s| (&*k, s)))
+    }
+}
+
+// Days serialize as an ordered list of dates each holding its sections
+fn days<S: Serializer>(days: &BTreeMap<DayKey, Vec<WeatherSection>>, serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct Day<'a> {
+        date: &'a DayKey,
+        sections: &'a [WeatherSection],
+    }
+
+    serializer.collect_seq(days.iter().map(|(date, sections)| Day { date, sections }))
+}
+
+fn stale_since<S: Serializer>(fetched: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match fetched {
+        Some(fetched) => rfc3339(fetched, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn local_time<S: Serializer>(time: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&time.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+
+// Optional groups of extra table columns, shown between snow and desc
+#[derive(Copy, Clone, Debug, Hash, Eq, Ord, PartialOrd, PartialEq)]
+pub enum ColumnGroup {
+    Range,
+    Pressure,
+    Sky,
+    Gust,
+    Meta,
+    Air,
+    Spread,
+}
+
+impl FromStr for ColumnGroup {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "range" => Ok(Self::Range),
+            "pressure" => Ok(Self::Pressure),
+            "sky" => Ok(Self::Sky),
+            "gust" => Ok(Self::Gust),
+            "meta" => Ok(Self::Meta),
+            "air" => Ok(Self::Air),
+            "spread" => Ok(Self::Spread),
+            _ => Err(ApiError::BadParse("unknown column group"))
+        }
+    }
+}
+
+impl ColumnGroup {
+    const ALL: [ColumnGroup; 7] = [Self::Range, Self::Pressure, Self::Sky, Self::Gust, Self::Meta, Self::Air, Self::Spread];
+
+    fn headings(&self) -> &'static [&'static str] {
+        match self {
+            Self::Range => &["min", "max"],
+            Self::Pressure => &["pres", "sea", "grnd"],
+            Self::Sky => &["cld%", "vis", "pop%", "prcp", "type"],
+            Self::Gust => &["gust"],
+            Self::Meta => &["pod", "id", "main", "icon", "utc"],
+            Self::Air => &["aqi", "pm2.5", "pm10", "o3", "no2", "so2", "co"],
+            Self::Spread => &["n", "t-lo", "t-hi", "t-conf", "p-lo", "p-hi", "p-conf"],
+        }
+    }
+}
+
+// Set of enabled column groups, ordered for a stable column layout
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Columns {
+    groups: BTreeSet<ColumnGroup>,
+}
+
+impl Columns {
+    // Generate Columns given user supplied comma separated groups, e.g. "range,sky" or "all"
+    pub fn load(groups: Option<&str>) -> Result<Columns, ApiError> {
+        let groups = match groups {
+            Some("all") => ColumnGroup::ALL.into_iter().collect(),
+            Some(list) => list.split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(ColumnGroup::from_str)
+                .collect::<Result<_, _>>()?,
+            None => BTreeSet::new(),
+        };
+
+        Ok(Columns { groups })
+    }
+
+    #[inline]
+    pub fn contains(&self, group: ColumnGroup) -> bool {
+        self.groups.contains(&group)
+    }
+
+    pub fn with(mut self, group: ColumnGroup) -> Self {
+        self.groups.insert(group);
+        self
+    }
+
+    // tabular spec, core columns then extra groups then description
+    fn spec(&self) -> String {
+        //                      1     2    3      4      5     6       7      8
+        let mut spec = String::from("{:>}    {:<}  {:<}   {:>}   {:>}  {:>}    {:>}   {:>}");
+
+        for group in &self.groups {
+            for _ in group.headings() {
+                spec.push_str("  {:>}");
+            }
+        }
+
+        spec.push_str("   {:<}");
+        spec
+    }
+
+    // Core headings then extra groups then description
+    fn headings(&self) -> Vec<&'static str> {
+        let core = ["day-hour", "temp", "feel", "hum", "wspd", "wdeg", "rain", "snow"];
+        let extra = self.groups.iter().flat_map(|g| g.headings().iter());
+
+        core.iter().chain(extra).chain(["desc"].iter()).copied().collect()
+    }
 }
 
 
 // Implement `Display` for `WeatherForecast` using tabular
 impl fmt::Display for WeatherForecast {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // Print each days table
-        //                             1     2    3      4      5     6       7         8
-        let mut table = Table::new("{:>}    {:<}  {:<}   {:>}   {:>}  {:>}    {:>}   {:<}");
+        let columns = self.shown_columns();
+
+        let mut table = match self.granularity {
+            Granularity::Minutely => Table::new("{:>}    {:>}"),
+            _ => Table::new(&columns.spec()),
+        };
+
+        table.add_heading(format!("\n{}", ansi(&self.location.describe(&self.clock), Tone::Location)));
+
+        if let Some(stale) = self.stale() {
+            table.add_heading(format!("\n{}", ansi(&stale, Tone::Stale)));
+        }
+
+        if let Some(ref current) = self.current {
+            table.add_heading(format!("\n{}", current.summary(ansi)));
+        }
+
+        for alert in &self.alerts {
+            table.add_heading(format!("\n{}", alert.banner(ansi)));
+        }
 
-        let loc = format!("\n{}", &self.location.to_string().cyan().bold());
-        table.add_heading(loc);
+        for (heading, sections) in self.tables() {
+            table.add_heading(format!("\n{}", ansi(&heading, Tone::Heading)));
+            table.add_row(self.headings(&columns).iter().fold(Row::new(), |row, h| row.with_cell(h)));
 
-        for (k,v) in &self.days {
-            table.add_heading(format!("\n{}", &k.to_string().italic().purple()));
-            table.add_heading("day-hour  temp    feel   hum   wspd  wdeg   rain   desc");
+            for (day, section) in sections {
+                let row = section.cells(&columns, self.granularity, day).iter()
+                    .fold(Row::new(), |row, (text, tone)| row.with_ansi_cell(tone.paint(text)));
 
-            for section in v {
-                section.display(&mut table)
+                table.add_row(row);
             }
         }
 
@@ -67,46 +582,484 @@ impl fmt::Display for WeatherForecast {
     }
 }
 
-impl From<WeatherList> for WeatherForecast {
-    fn from(data: WeatherList) -> Self {
-        data.transform()
+// Renders a forecast as a document for pasting elsewhere,
+// alongside the terminal table of the Display impl above
+pub trait Render {
+    fn render(&self, forecast: &WeatherForecast) -> String;
+}
+
+// GitHub flavored markdown, a table per day
+pub struct Markdown;
+
+impl Render for Markdown {
+    fn render(&self, forecast: &WeatherForecast) -> String {
+        let columns = forecast.shown_columns();
+        let plain = |text: &str, _| markdown(text);
+        let mut doc = Vec::new();
+
+        let describe = forecast.location.describe(&forecast.clock);
+        let mut lines = describe.lines();
+        doc.push(format!("## {}", markdown(lines.next().unwrap_or_default())));
+        doc.extend(lines.map(markdown));
+
+        if let Some(stale) = forecast.stale() {
+            doc.push(format!("\n**{}**", stale));
+        }
+
+        if let Some(ref current) = forecast.current {
+            doc.push(format!("\n{}", current.summary(plain).replace('\n', "  \n")));
+        }
+
+        for alert in &forecast.alerts {
+            let banner = alert.banner(plain);
+            doc.push(format!("\n{}", banner.lines().map(|l| format!("> {}", l.trim())).collect::<Vec<_>>().join("\n")));
+        }
+
+        for (heading, sections) in forecast.tables() {
+            let headings = forecast.headings(&columns);
+            let align: Vec<&str> = headings.iter().map(|h| if *h == "desc" { ":--" } else { "--:" }).collect();
+
+            doc.push(format!("\n### {}\n", heading));
+            doc.push(format!("| {} |", headings.join(" | ")));
+            doc.push(format!("|{}|", align.join("|")));
+
+            for (day, section) in sections {
+                let cells: Vec<String> = section.cells(&columns, forecast.granularity, day).into_iter()
+                    .map(|(text, _)| markdown(&text))
+                    .collect();
+
+                doc.push(format!("| {} |", cells.join(" | ")));
+            }
+        }
+
+        doc.join("\n")
+    }
+}
+
+// Text kept literal in a table cell or heading, where a pipe ends
+// the cell, a backslash escapes what follows and a newline the row
+fn markdown(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('\n', " ")
+}
+
+// Self-contained html page, inline styles mirror the terminal colors
+// so it survives email clients that drop style sheets
+pub struct Html;
+
+const HTML_PAGE: &str = "background:#1e1e1e;color:#e5e5e5;font-family:Menlo,Consolas,monospace;padding:1em";
+const HTML_TABLE: &str = "border-collapse:collapse;margin-bottom:1em";
+const HTML_CELL: &str = "padding:2px 10px;white-space:nowrap";
+
+impl Render for Html {
+    fn render(&self, forecast: &WeatherForecast) -> String {
+        let columns = forecast.shown_columns();
+        let mut doc = Vec::new();
+
+        doc.push(String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">"));
+        doc.push(format!("<title>{} {}</title>\n</head>", escape(forecast.location.name()), escape(forecast.location.country())));
+        doc.push(format!("<body style=\"{}\">", HTML_PAGE));
+        doc.push(paragraph(&forecast.location.describe(&forecast.clock), Tone::Location));
+
+        if let Some(stale) = forecast.stale() {
+            doc.push(paragraph(&stale, Tone::Stale));
+        }
+
+        if let Some(ref current) = forecast.current {
+            doc.push(format!("<p>{}</p>", current.summary(span).replace('\n', "<br>\n")));
+        }
+
+        for alert in &forecast.alerts {
+            doc.push(format!("<p>{}</p>", alert.banner(span).replace('\n', "<br>\n")));
+        }
+
+        for (heading, sections) in forecast.tables() {
+            doc.push(paragraph(&heading, Tone::Heading));
+            doc.push(format!("<table style=\"{}\">", HTML_TABLE));
+
+            let headings = forecast.headings(&columns);
+            let row = headings.iter()
+                .map(|h| format!("<th style=\"{};{}\">{}</th>", HTML_CELL, align(h), escape(h)))
+                .collect::<String>();
+            doc.push(format!("<tr>{}</tr>", row));
+
+            for (day, section) in sections {
+                let row = section.cells(&columns, forecast.granularity, day).iter()
+                    .zip(&headings)
+                    .map(|((text, tone), h)| format!("<td style=\"{};{};{}\">{}</td>", HTML_CELL, align(h), tone.css(), escape(text)))
+                    .collect::<String>();
+                doc.push(format!("<tr>{}</tr>", row));
+            }
+
+            doc.push(String::from("</table>"));
+        }
+
+        doc.push(String::from("</body>\n</html>"));
+        doc.join("\n")
+    }
+}
+
+// Styled inline text, plain text needs no span
+fn span(text: &str, tone: Tone) -> String {
+    match tone {
+        Tone::Plain => escape(text),
+        _ => format!("<span style=\"{}\">{}</span>", tone.css(), escape(text)),
+    }
+}
+
+fn paragraph(text: &str, tone: Tone) -> String {
+    format!("<p style=\"{}\">{}</p>", tone.css(), escape(text).replace('\n', "<br>\n"))
+}
+
+// Descriptions read left to right like the terminal table, numbers line up right
+#[inline]
+fn align(heading: &str) -> &'static str {
+    if heading == "desc" { "text-align:left" } else { "text-align:right" }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl WeatherForecast {
+    // Daily rows always show their low and high, and
+    // ensemble means always show how far members disagree
+    fn shown_columns(&self) -> Columns {
+        let mut columns = match self.granularity {
+            Granularity::Daily => self.columns.clone().with(ColumnGroup::Range),
+            _ => self.columns.clone(),
+        };
+
+        if self.sections().any(|(_, s)| s.spread.is_some()) {
+            columns = columns.with(ColumnGroup::Spread);
+        }
+
+        columns
+    }
+
+    fn stale(&self) -> Option<String> {
+        self.stale_since.map(|fetched| format!("Offline, stale since {}", datetime(fetched, self.location.timezone(), &self.clock)))
+    }
+
+    // Headed tables of sections, a single table of minutes or days, otherwise one per day
+    fn tables(&self) -> Vec<(String, Vec<(&DayKey, &WeatherSection)>)> {
+        match self.granularity {
+            Granularity::Minutely | Granularity::Daily => vec![(self.granularity.to_string(), self.sections().collect())],
+            Granularity::Hourly | Granularity::ThreeHour => self.days.iter()
+                .map(|(k, v)| (k.to_string(), v.iter().map(|s| (k, s)).collect()))
+                .collect(),
+        }
+    }
+
+    fn headings(&self, columns: &Columns) -> Vec<&'static str> {
+        match self.granularity {
+            Granularity::Minutely => vec!["time", "prcp mm/h"],
+            _ => columns.headings(),
+        }
+    }
+}
+
+// Standalone right now display, used by --now
+impl fmt::Display for CurrentConditions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "\n{}", ansi(&self.place, Tone::Location))?;
+        write!(f, "{}", self.summary(ansi))
+    }
+}
+
+impl CurrentConditions {
+    // Cached conditions served offline, since is when they were fetched
+    pub fn with_stale_since(mut self, since: String) -> Self {
+        self.place = format!("{}  (offline, stale since {})", self.place, since);
+        self
+    }
+
+    // Note the provider that served the conditions after the place name
+    pub fn with_source(mut self, source: &'static str) -> Self {
+        self.place = format!("{}  via {}", self.place, source);
+        self
+    }
+
+    pub fn with_air_quality(mut self, air_quality: Option<AirQuality>) -> Self {
+        self.air_quality = air_quality;
+        self
+    }
+
+    // Compact two line block of the current conditions
+    fn summary<P: Fn(&str, Tone) -> String>(&self, paint: P) -> String {
+        let desc = self.conditions.iter()
+            .map(|c| c.description.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ");
+
+        let mut precip = String::new();
+
+        if let Some(rain) = self.rain_one_hour {
+            precip.push_str(&format!("  rain {:.2}", rain));
+        }
+
+        if let Some(snow) = self.snow_one_hour {
+            precip.push_str(&format!("  snow {:.2}", snow));
+        }
+
+        let gust = self.wind_gust.map(|g| format!(" gust {:.2}", g)).unwrap_or_default();
+        // visibility is reported in meters, capped at 10 km
+        let visibility = self.visibility.map(|v| format!("  vis {:.1} km", v as f32 / 1000.0)).unwrap_or_default();
+        let air = self.air_quality.map(|a| format!("  aqi {}", paint(&a.label(), Tone::Air(a.aqi)))).unwrap_or_default();
+
+        format!("{}  {}  {}\n{}  {}  hum {}%  {} hPa  wind {:.2}{} @ {:03}  clouds {}%{}{}{}",
+                paint("Now", Tone::Now),
+                paint(&self.datetime, Tone::Italic),
+                paint(&desc, Tone::Desc),
+                paint(&format!("{:.2}", self.temp), Tone::Temp),
+                paint(&format!("(feels {:.2}, {:.0}-{:.0})", self.feels_like, self.temp_min, self.temp_max), Tone::FeelsRange),
+                paint(&self.humidity.to_string(), Tone::Humidity),
+                paint(&self.pressure.to_string(), Tone::Pressure),
+                self.wind_speed,
+                gust,
+                self.wind_deg,
+                self.clouds,
+                visibility,
+                paint(&precip, Tone::Precip),
+                air)
+    }
+}
+
+impl TryFrom<WeatherList> for WeatherForecast {
+    type Error = ApiError;
+
+    fn try_from(data: WeatherList) -> Result<Self, Self::Error> {
+        data.transform(Clock::default(), &HourFilter::default())
     }
 }
 
 
 impl WeatherSection {
-    pub(crate) fn new(
-        day_of_week: String, hour: u8, datetime: String, temp: f32, feels_like: f32,
-        humidity: u16, description: String, wind_speed: f32, wind_deg: u16,
-        rain_three_hour: Option<f32>,
-    ) -> Self {
-        WeatherSection {
-            day_of_week, hour, datetime, temp, feels_like, humidity,
-            description, wind_speed, wind_deg, rain_three_hour
-        }
-    }
-
-    // Implicit override of fmt::Display since it accepts table
-    pub(crate) fn display(&self, table: &mut Table) {
-        let day_hour = format!("{}-{:02}", &self.day_of_week, &self.hour).bold();
-        let temp = format!("{:.2}", &self.temp).yellow();
-        let feels = format!("{:.2}", &self.feels_like).bright_yellow().bold();
-        let hum = format!("{}", &self.humidity).green();
-        let wspeed = format!("{:.2}", &self.wind_speed).blue().on_bright_blue();
-        let wdeg = format!("{:03}", &self.wind_deg).blue().on_bright_blue();
-        let rainfall_3h: f32 = self.rain_three_hour.unwrap_or_default();
-        let rainfall = format!("{:.2}", &rainfall_3h).blue().italic().on_bright_white();
-        let desc = &self.description.bright_green().bold().italic();
-
-        table.add_row(Row::new()
-                      .with_cell(day_hour) // 1
-                      .with_cell(temp) // 2
-                      .with_cell(feels)  // 3
-                      .with_cell(hum) // 4
-                      .with_cell(wspeed) // 5
-                      .with_cell(wdeg) // 6
-                      .with_cell(rainfall) // 7
-                      .with_cell(desc) // 8
-        );
+    // Section with only its time fields set, for providers that
+    // fill in the remaining fields from what they report
+    pub(crate) fn at(clock: &Clock, value: u64, city_offset: i32) -> (DayKey, WeatherSection) {
+        let datetime = clock.localize(value, city_offset);
+        let utc = Clock::Utc.localize(value, 0);
+
+        (DayKey::from(datetime.naive_local().date()), WeatherSection {
+            timestamp: value,
+            local: datetime,
+            day_of_week: datetime.format("%a").to_string(),
+            hour: datetime.hour() as u8,
+            datetime: datetime.format("%Y-%m-%d %H:%M").to_string(),
+            dt_txt: utc.format("%Y-%m-%d %H:%M:%S").to_string(),
+            temp: 0.0,
+            feels_like: 0.0,
+            temp_min: 0.0,
+            temp_max: 0.0,
+            pressure: 0,
+            sea_level: None,
+            grnd_level: None,
+            humidity: 0,
+            conditions: Vec::new(),
+            clouds: 0,
+            visibility: None,
+            pop: 0.0,
+            wind_speed: 0.0,
+            wind_deg: 0,
+            wind_gust: None,
+            rain_volume: None,
+            snow_volume: None,
+            precipitation: 0.0,
+            accumulation: None,
+            precip_type: Precipitation::None,
+            daytime: false,
+            air_quality: None,
+            spread: None,
+        })
+    }
+
+    // Table cells of the section with their styles, core columns
+    // then extra groups then description
+    pub(crate) fn cells(&self, columns: &Columns, granularity: Granularity, day: &DayKey) -> Vec<(String, Tone)> {
+        let day_hour = match granularity {
+            // minutely sections only carry precipitation
+            Granularity::Minutely => return vec![
+                (self.datetime.get(11..).unwrap_or_default().to_owned(), Tone::Day),
+                (format!("{:.2}", &self.precipitation), Tone::Rain),
+            ],
+            Granularity::Daily => format!("{} {:02}-{:02}", &self.day_of_week, day.month(), day.day()),
+            _ => format!("{}-{:02}", &self.day_of_week, &self.hour),
+        };
+
+        let mut cells = vec![
+            (day_hour, Tone::Day), // 1
+            (format!("{:.2}", &self.temp), Tone::Temp), // 2
+            (format!("{:.2}", &self.feels_like), Tone::Feels), // 3
+            (format!("{}", &self.humidity), Tone::Humidity), // 4
+            (format!("{:.2}", &self.wind_speed), Tone::Wind), // 5
+            (format!("{:03}", &self.wind_deg), Tone::Wind), // 6
+            (format!("{:.2}", self.rain_volume.unwrap_or_default()), Tone::Rain), // 7
+            (format!("{:.2}", self.snow_volume.unwrap_or_default()), Tone::Snow), // 8
+        ];
+
+        if columns.contains(ColumnGroup::Range) {
+            cells.push((format!("{:.2}", &self.temp_min), Tone::Temp));
+            cells.push((format!("{:.2}", &self.temp_max), Tone::Temp));
+        }
+
+        if columns.contains(ColumnGroup::Pressure) {
+            cells.push((format!("{}", &self.pressure), Tone::Pressure));
+            cells.push((optional(self.sea_level), Tone::Pressure));
+            cells.push((optional(self.grnd_level), Tone::Pressure));
+        }
+
+        if columns.contains(ColumnGroup::Sky) {
+            cells.push((format!("{}", &self.clouds), Tone::Sky));
+            cells.push((optional(self.visibility), Tone::Sky));
+            cells.push((format!("{:.0}", self.pop * 100.0), Tone::Precip));
+            cells.push((format!("{:.2}", &self.precipitation), Tone::Precip));
+            cells.push((self.precip_type.to_string(), Tone::Plain));
+        }
+
+        if columns.contains(ColumnGroup::Gust) {
+            cells.push((optional(self.wind_gust.map(|g| format!("{:.2}", g))), Tone::Wind));
+        }
+
+        if columns.contains(ColumnGroup::Meta) {
+            cells.push((String::from(if self.daytime { "d" } else { "n" }), Tone::Plain));
+            cells.push((self.joined("/", |c| c.id.to_string()), Tone::Plain));
+            cells.push((self.joined("/", |c| c.group.clone()), Tone::Plain));
+            cells.push((self.joined("/", |c| c.icon.clone()), Tone::Plain));
+            cells.push((self.dt_txt.clone(), Tone::Plain));
+        }
+
+        if columns.contains(ColumnGroup::Air) {
+            match self.air_quality {
+                Some(air) => {
+                    cells.push((air.label(), Tone::Air(air.aqi)));
+                    for value in [air.pm2_5, air.pm10, air.o3, air.no2, air.so2, air.co] {
+                        cells.push((format!("{:.1}", value), Tone::Plain));
+                    }
+                },
+                None => {
+                    for _ in ColumnGroup::Air.headings() {
+                        cells.push((String::from("-"), Tone::Plain));
+                    }
+                },
+            }
+        }
+
+        if columns.contains(ColumnGroup::Spread) {
+            match self.spread {
+                Some(spread) => {
+                    cells.push((spread.members.to_string(), Tone::Plain));
+                    cells.push((format!("{:.2}", spread.temp.0), Tone::Temp));
+                    cells.push((format!("{:.2}", spread.temp.1), Tone::Temp));
+                    cells.push((spread.temp_confidence.to_string(), Tone::Confidence(spread.temp_confidence)));
+                    cells.push((format!("{:.2}", spread.precipitation.0), Tone::Precip));
+                    cells.push((format!("{:.2}", spread.precipitation.1), Tone::Precip));
+                    cells.push((spread.precip_confidence.to_string(), Tone::Confidence(spread.precip_confidence)));
+                },
+                None => {
+                    for _ in ColumnGroup::Spread.headings() {
+                        cells.push((String::from("-"), Tone::Plain));
+                    }
+                },
+            }
+        }
+
+        cells.push((self.joined(", ", |c| c.description.clone()), Tone::Desc)); // last
+        cells
+    }
+
+    // Join a field across all conditions reported for this time slot
+    pub(crate) fn joined<F: Fn(&Condition) -> String>(&self, sep: &str, field: F) -> String {
+        self.conditions.iter().map(field).collect::<Vec<String>>().join(sep)
+    }
+}
+
+// Display missing optional values as a dash
+fn optional<T: fmt::Display>(value: Option<T>) -> String {
+    value.map_or_else(|| String::from("-"), |v| v.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{AirPollutionList, CurrentWeather};
+    use crate::filter::Preset;
+    use crate::mock_json::{JSON_AIR_POLLUTION_RESPONSE, JSON_CURRENT_RESPONSE, JSON_RESPONSE};
+
+    fn plain(text: &str, _: Tone) -> String {
+        text.to_owned()
+    }
+
+    #[test]
+    fn merges_air_quality_by_timestamp() {
+        let air = AirPollutionList::parse(JSON_AIR_POLLUTION_RESPONSE).unwrap().transform();
+        let forecast = WeatherList::parse(JSON_RESPONSE).unwrap()
+            .transform(Clock::City, &HourFilter::from(Preset::All))
+            .unwrap()
+            .with_air_quality(&air);
+        let readings: Vec<(u64, Option<u8>)> = forecast.sections()
+            .map(|(_, s)| (s.timestamp, s.air_quality.map(|a| a.aqi)))
+            .collect();
+
+        assert_eq!(readings[0], (1655586000, Some(2)));
+        assert_eq!(readings[1], (1655596800, Some(4)));
+        assert!(readings[2..].iter().all(|(_, aqi)| aqi.is_none()));
+
+        let merged = forecast.sections().nth(1).unwrap().1.air_quality.unwrap();
+        assert_eq!((merged.pm2_5, merged.pm10, merged.o3), (31.7, 88.4, 165.5));
+        assert_eq!(merged.label(), "4 poor");
+    }
+
+    #[test]
+    fn latest_air_quality_is_newest_reading() {
+        let latest = AirPollutionList::parse(JSON_AIR_POLLUTION_RESPONSE).unwrap().latest().unwrap();
+
+        assert_eq!((latest.aqi, latest.band()), (4, "poor"));
+    }
+
+    // Forecast whose place and first condition hold characters with meaning in markup
+    fn awkward() -> WeatherForecast {
+        let mut forecast = WeatherList::parse(JSON_RESPONSE).unwrap()
+            .transform(Clock::City, &HourFilter::from(Preset::All))
+            .unwrap()
+            .with_place("Fish & Chips <\"Pipe|Back\\slash\">", "GB");
+        let section = forecast.days.values_mut().next().unwrap().first_mut().unwrap();
+        section.conditions[0].description = String::from("rain | hail \\ sleet\n<b>snow</b> & \"ice\"");
+
+        forecast
+    }
+
+    #[test]
+    fn renders_markdown_text_literally() {
+        let doc = Markdown.render(&awkward());
+        let row = doc.lines().find(|l| l.contains("hail")).unwrap();
+
+        assert!(doc.starts_with("## Fish & Chips <\"Pipe\\|Back\\\\slash\"> GB ["), "{}", doc);
+        assert!(row.starts_with("| ") && row.ends_with(" |"), "{}", row);
+        assert!(row.contains("rain \\| hail \\\\ sleet <b>snow</b> & \"ice\""), "{}", row);
+    }
+
+    #[test]
+    fn renders_html_text_escaped() {
+        let doc = Html.render(&awkward());
+
+        assert!(doc.contains("<title>Fish &amp; Chips &lt;&quot;Pipe|Back\\slash&quot;&gt; GB</title>"), "{}", doc);
+        assert!(doc.contains("rain | hail \\ sleet\n&lt;b&gt;snow&lt;/b&gt; &amp; &quot;ice&quot;</td>"), "{}", doc);
+        assert!(!doc.contains("<b>") && !doc.contains("Chips <"));
+    }
+
+    #[test]
+    fn summarizes_current_conditions() {
+        let current = CurrentWeather::parse(JSON_CURRENT_RESPONSE).unwrap()
+            .transform(Clock::City)
+            .unwrap()
+            .with_air_quality(AirPollutionList::parse(JSON_AIR_POLLUTION_RESPONSE).unwrap().latest());
+
+        assert_eq!(current.summary(plain), "Now  Sat 06-18 13:12  light rain, mist\n\
+            91.60  (feels 89.90, 89-94)  hum 31%  1010 hPa  wind 18.41 gust 26.46 @ 230  clouds 75%  vis 10.0 km  rain 0.31  aqi 4 poor");
     }
 }
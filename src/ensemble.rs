@@ -0,0 +1,266 @@
+use std::collections::BTreeMap;
+
+use crate::backend::{DayKey, Granularity};
+use crate::display::{Confidence, CurrentConditions, Spread, WeatherForecast, WeatherSection};
+use crate::provider::{Query, WeatherProvider};
+use crate::request::{ApiError, Units};
+
+// Ensemble provider, queries several providers for the same location and
+// replaces each section with the mean of the members reporting that time.
+// The spread of member values and a confidence rating are kept alongside,
+// as how much the forecasts disagree matters as much as any single figure.
+// Sections are aligned by timestamp, or by day for daily forecasts since
+// providers stamp their days at different hours.
+
+// Member disagreement (°C, mm) still rated high then medium confidence
+const TEMP_BANDS: (f32, f32) = (2.0, 4.0);
+const PRECIP_BANDS: (f32, f32) = (1.0, 3.0);
+
+// Precipitation below this is treated as dry when comparing members
+const DRY: f32 = 0.1;
+
+pub struct Ensemble {
+    members: Vec<Box<dyn WeatherProvider>>,
+}
+
+impl Ensemble {
+    pub fn new(members: Vec<Box<dyn WeatherProvider>>) -> Ensemble {
+        Ensemble { members }
+    }
+}
+
+impl WeatherProvider for Ensemble {
+    fn name(&self) -> &'static str {
+        "ensemble"
+    }
+
+    // Current conditions from the first member able to supply them
+    fn current(&self, query: &Query) -> Result<CurrentConditions, ApiError> {
+        let mut error = ApiError::BadParse("ensemble has no members");
+
+        for member in &self.members {
+            match member.current(query) {
+                Ok(current) => return Ok(current),
+                Err(e) => error = e,
+            }
+        }
+
+        Err(error)
+    }
+
+    // The first member to respond supplies the layout, members
+    // that fail are left out unless every one of them does
+    fn forecast(&self, query: &Query) -> Result<WeatherForecast, ApiError> {
+        let mut forecasts = Vec::new();
+        let mut error = ApiError::BadParse("ensemble has no members");
+
+        for member in &self.members {
+            match member.forecast(query) {
+                Ok(forecast) => forecasts.push(forecast),
+                Err(e) => error = e,
+            }
+        }
+
+        if forecasts.is_empty() {
+            return Err(error)
+        }
+
+        let mut base = forecasts.remove(0);
+        let granularity = base.granularity();
+
+        // every member's sections keyed for alignment
+        let others: Vec<BTreeMap<(DayKey, u64), &WeatherSection>> = forecasts.iter()
+            .map(|f| f.sections().map(|(day, s)| (key(granularity, day, s), s)).collect())
+            .collect();
+
+        for (day, section) in base.sections_mut() {
+            let k = key(granularity, day, section);
+            let peers: Vec<&WeatherSection> = others.iter().filter_map(|o| o.get(&k).copied()).collect();
+
+            combine(section, &peers, query.units);
+        }
+
+        Ok(base)
+    }
+}
+
+#[inline]
+fn key(granularity: Granularity, day: &DayKey, section: &WeatherSection) -> (DayKey, u64) {
+    match granularity {
+        Granularity::Daily => (*day, 0),
+        _ => (*day, section.timestamp),
+    }
+}
+
+// Replaces the section's values with the mean across it and its peers,
+// recording the range of temperature and precipitation. Amounts are scaled
+// onto the period of the section's own before combining, e.g. a member's
+// 3 hour total counts a third against an hourly one, and members that give
+// no amounts at all are left out of the precipitation figures
+fn combine(section: &mut WeatherSection, peers: &[&WeatherSection], units: Units) {
+    let own = section.clone();
+    let members: Vec<&WeatherSection> = peers.iter().copied().chain([&own]).collect();
+    let n = members.len() as f32;
+    let mean = |field: fn(&WeatherSection) -> f32| members.iter().map(|s| field(s)).sum::<f32>() / n;
+    let range = |values: &[f32]| values.iter().fold((f32::MAX, f32::MIN), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+
+    let period = own.accumulation.or_else(|| peers.iter().find_map(|s| s.accumulation));
+    let amounts: Vec<Amounts> = members.iter()
+        .filter_map(|s| Some(Amounts::of(s, period? as f32 / s.accumulation? as f32)))
+        .collect();
+    let reporting = amounts.len().max(1) as f32;
+    let volume = |field: fn(&Amounts) -> Option<f32>| if amounts.iter().any(|a| field(a).is_some()) {
+        Some(amounts.iter().map(|a| field(a).unwrap_or_default()).sum::<f32>() / reporting)
+    } else {
+        None
+    };
+
+    let temps: Vec<f32> = members.iter().map(|s| s.temp).collect();
+    let temp = range(&temps);
+    let totals: Vec<f32> = amounts.iter().map(|a| a.precipitation).collect();
+    let precipitation = if totals.is_empty() { (0.0, 0.0) } else { range(&totals) };
+    let wet = totals.iter().filter(|p| **p >= DRY).count();
+
+    // temperature spreads are rated in celsius
+    let temp_spread = match units {
+        Units::Imperial => (temp.1 - temp.0) / 1.8,
+        Units::Metric => temp.1 - temp.0,
+    };
+
+    // members split between wet and dry is the least certain outcome
+    let precip_confidence = if wet != 0 && wet != totals.len() {
+        Confidence::Low
+    } else {
+        rate(precipitation.1 - precipitation.0, PRECIP_BANDS)
+    };
+
+    let spread = Spread {
+        members: members.len(),
+        temp,
+        temp_confidence: rate(temp_spread, TEMP_BANDS),
+        precipitation,
+        precip_confidence,
+    };
+
+    section.temp = mean(|s| s.temp);
+    section.feels_like = mean(|s| s.feels_like);
+    section.temp_min = mean(|s| s.temp_min);
+    section.temp_max = mean(|s| s.temp_max);
+    section.humidity = mean(|s| s.humidity as f32).round() as u16;
+    section.wind_speed = mean(|s| s.wind_speed);
+    section.pop = mean(|s| s.pop);
+    section.precipitation = totals.iter().sum::<f32>() / reporting;
+    section.rain_volume = volume(|a| a.rain);
+    section.snow_volume = volume(|a| a.snow);
+    section.accumulation = period;
+    section.spread = Some(spread);
+}
+
+// A member's amounts scaled onto the combined section's period
+struct Amounts {
+    precipitation: f32,
+    rain: Option<f32>,
+    snow: Option<f32>,
+}
+
+impl Amounts {
+    fn of(section: &WeatherSection, scale: f32) -> Amounts {
+        Amounts {
+            precipitation: section.precipitation * scale,
+            rain: section.rain_volume.map(|v| v * scale),
+            snow: section.snow_volume.map(|v| v * scale),
+        }
+    }
+}
+
+#[inline]
+fn rate(spread: f32, (high, medium): (f32, f32)) -> Confidence {
+    if spread <= high {
+        Confidence::High
+    } else if spread <= medium {
+        Confidence::Medium
+    } else {
+        Confidence::Low
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Clock;
+
+    // Member section at noon with the temperature and amount over its period
+    fn member(temp: f32, rain: f32, accumulation: Option<u64>) -> WeatherSection {
+        let (_, mut section) = WeatherSection::at(&Clock::Utc, 1655553600, 0);
+
+        section.temp = temp;
+        section.precipitation = rain;
+        section.rain_volume = Some(rain).filter(|r| *r > 0.0);
+        section.accumulation = accumulation;
+        section
+    }
+
+    fn combined(own: WeatherSection, peers: &[WeatherSection], units: Units) -> WeatherSection {
+        let mut section = own;
+        combine(&mut section, &peers.iter().collect::<Vec<_>>(), units);
+        section
+    }
+
+    #[test]
+    fn averages_members_and_rates_temperature_spread() {
+        let hour = Some(3600);
+        let close = combined(member(20.0, 0.0, hour), &[member(21.0, 0.0, hour), member(22.0, 0.0, hour)], Units::Metric);
+        let spread = close.spread.unwrap();
+
+        assert_eq!(close.temp, 21.0);
+        assert_eq!((spread.members, spread.temp, spread.temp_confidence), (3, (20.0, 22.0), Confidence::High));
+        assert_eq!((spread.precipitation, spread.precip_confidence), ((0.0, 0.0), Confidence::High));
+
+        let apart = combined(member(20.0, 0.0, hour), &[member(23.0, 0.0, hour)], Units::Metric);
+        assert_eq!(apart.spread.unwrap().temp_confidence, Confidence::Medium);
+
+        // 9°F is 5°C
+        let far = combined(member(70.0, 0.0, hour), &[member(79.0, 0.0, hour)], Units::Imperial);
+        assert_eq!(far.spread.unwrap().temp_confidence, Confidence::Low);
+    }
+
+    #[test]
+    fn rates_precipitation_spread() {
+        let hour = Some(3600);
+        let medium = combined(member(20.0, 1.0, hour), &[member(20.0, 3.5, hour)], Units::Metric);
+        let low = combined(member(20.0, 1.0, hour), &[member(20.0, 5.0, hour)], Units::Metric);
+        let split = combined(member(20.0, 0.0, hour), &[member(20.0, 0.5, hour)], Units::Metric);
+
+        assert_eq!(medium.spread.unwrap().precip_confidence, Confidence::Medium);
+        assert_eq!(medium.precipitation, 2.25);
+        assert_eq!(low.spread.unwrap().precip_confidence, Confidence::Low);
+        // wet against dry is low however small the difference
+        assert_eq!(split.spread.unwrap().precip_confidence, Confidence::Low);
+    }
+
+    #[test]
+    fn scales_amounts_onto_the_sections_period() {
+        let hourly = combined(member(20.0, 0.5, Some(3600)), &[member(20.0, 3.0, Some(3 * 3600))], Units::Metric);
+        let spread = hourly.spread.unwrap();
+
+        // 3.0 over 3 hours is 1.0 over the hour
+        assert_eq!(spread.precipitation, (0.5, 1.0));
+        assert_eq!(spread.precip_confidence, Confidence::High);
+        assert_eq!((hourly.precipitation, hourly.rain_volume), (0.75, Some(0.75)));
+        assert_eq!(hourly.accumulation, Some(3600));
+    }
+
+    #[test]
+    fn leaves_members_without_amounts_out_of_precipitation() {
+        let dry_peer = combined(member(20.0, 2.0, Some(3600)), &[member(22.0, 0.0, None)], Units::Metric);
+        let spread = dry_peer.spread.unwrap();
+
+        assert_eq!((spread.members, dry_peer.temp), (2, 21.0));
+        assert_eq!((spread.precipitation, spread.precip_confidence), ((2.0, 2.0), Confidence::High));
+        assert_eq!((dry_peer.precipitation, dry_peer.rain_volume), (2.0, Some(2.0)));
+
+        // a section without amounts takes its period from the peers
+        let nws = combined(member(22.0, 0.0, None), &[member(20.0, 2.0, Some(3600))], Units::Metric);
+        assert_eq!((nws.precipitation, nws.accumulation), (2.0, Some(3600)));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::display::{CurrentConditions, WeatherForecast};
+use crate::provider::{Query, WeatherProvider};
+use crate::request::ApiError;
+
+// Failover provider, tries each provider in turn while the previous ones
+// are suffering an outage (see ApiError::is_outage). Other errors, e.g. no
+// matching place, are returned straight away as the next provider would
+// likely hit them too. The provider that served the data is noted in the
+// location header.
+
+pub struct Failover {
+    chain: Vec<Box<dyn WeatherProvider>>,
+}
+
+impl Failover {
+    pub fn new(chain: Vec<Box<dyn WeatherProvider>>) -> Failover {
+        Failover { chain }
+    }
+
+    // First result not lost to an outage, the last outage otherwise
+    fn attempt<T, F>(&self, fetch: F) -> Result<T, ApiError>
+    where F: Fn(&dyn WeatherProvider) -> Result<T, ApiError>
+    {
+        let mut error = ApiError::BadParse("failover has no providers");
+
+        for provider in &self.chain {
+            match fetch(provider.as_ref()) {
+                Err(e) if e.is_outage() => error = e,
+                result => return result,
+            }
+        }
+
+        Err(error)
+    }
+}
+
+impl WeatherProvider for Failover {
+    fn name(&self) -> &'static str {
+        "failover"
+    }
+
+    fn current(&self, query: &Query) -> Result<CurrentConditions, ApiError> {
+        self.attempt(|p| Ok(p.current(query)?.with_source(p.name())))
+    }
+
+    fn forecast(&self, query: &Query) -> Result<WeatherForecast, ApiError> {
+        self.attempt(|p| Ok(p.forecast(query)?.with_source(p.name())))
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::request::ApiError;
+
+// Module decides which forecast hours are kept when
+// grouping weather sections into days.
+
+// Named hour presets selectable from the cli
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Preset {
+    All,
+    Daytime,
+    Waking,
+    Night,
+}
+
+impl FromStr for Preset {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "daytime" => Ok(Self::Daytime),
+            "waking" => Ok(Self::Waking),
+            "night" => Ok(Self::Night),
+            _ => Err(ApiError::BadParse("unknown hours preset"))
+        }
+    }
+}
+
+impl Preset {
+    // Hours of the day (on the chosen clock) each preset keeps
+    fn hours(&self) -> Option<HashSet<u8>> {
+        match self {
+            Self::All => None,
+            Self::Daytime => Some((6..=18).collect()),
+            Self::Waking => Some((5..=23).collect()),
+            Self::Night => Some((18..=23).chain(0..=6).collect()),
+        }
+    }
+}
+
+
+// Hour filter built from a preset plus explicit include/exclude sets,
+// optionally restricted to the hours between sunrise and sunset
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HourFilter {
+    include: Option<HashSet<u8>>,
+    exclude: HashSet<u8>,
+    daylight: bool,
+}
+
+impl Default for HourFilter {
+    fn default() -> Self { HourFilter::from(Preset::Waking) }
+}
+
+impl From<Preset> for HourFilter {
+    fn from(preset: Preset) -> Self {
+        HourFilter {
+            include: preset.hours(),
+            exclude: HashSet::new(),
+            daylight: false,
+        }
+    }
+}
+
+impl HourFilter {
+    // Builder style helpers, include replaces the preset hours
+    pub fn include(mut self, hours: HashSet<u8>) -> Self {
+        self.include = Some(hours);
+        self
+    }
+
+    pub fn exclude(mut self, hours: HashSet<u8>) -> Self {
+        self.exclude = hours;
+        self
+    }
+
+    pub fn daylight(mut self, daylight: bool) -> Self {
+        self.daylight = daylight;
+        self
+    }
+
+    // Generate HourFilter given user supplied cli args
+    pub fn load(preset: Option<&str>, include: Option<&str>, exclude: Option<&str>, daylight: bool) -> Result<HourFilter, ApiError> {
+        let mut filter = match preset {
+            Some(p) => HourFilter::from(Preset::from_str(p)?),
+            None => HourFilter::default(),
+        };
+
+        if let Some(hours) = include {
+            filter = filter.include(parse_hours(hours)?);
+        }
+
+        if let Some(hours) = exclude {
+            filter = filter.exclude(parse_hours(hours)?);
+        }
+
+        Ok(filter.daylight(daylight))
+    }
+
+    // Whether a section at the given hour should be kept,
+    // daylight is the (sunrise, sunset) hour window when daylight mode is on
+    pub fn keep(&self, hour: u8, daylight: Option<(u8, u8)>) -> bool {
+        let included = self.include.as_ref().is_none_or(|set| set.contains(&hour));
+        let excluded = self.exclude.contains(&hour);
+
+        let lit = match daylight {
+            Some((rise, set)) if self.daylight => {
+                // window may wrap past midnight on non-city clocks
+                if rise <= set { rise <= hour && hour <= set } else { hour >= rise || hour <= set }
+            },
+            _ => true,
+        };
+
+        included && !excluded && lit
+    }
+}
+
+// Parses comma separated hour list e.g. "0,3,21"
+pub fn parse_hours(s: &str) -> Result<HashSet<u8>, ApiError> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(|t| match t.parse::<u8>() {
+            Ok(h) if h < 24 => Ok(h),
+            _ => Err(ApiError::BadParse("hours must be comma separated values 0-23")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hours of the day the filter keeps, daylight mode given the window
+    fn kept(filter: &HourFilter, daylight: Option<(u8, u8)>) -> Vec<u8> {
+        (0..24).filter(|h| filter.keep(*h, daylight)).collect()
+    }
+
+    #[test]
+    fn presets_keep_their_hours() {
+        assert_eq!(kept(&HourFilter::from(Preset::All), None), (0..24).collect::<Vec<_>>());
+        assert_eq!(kept(&HourFilter::from(Preset::Daytime), None), (6..=18).collect::<Vec<_>>());
+        assert_eq!(kept(&HourFilter::default(), None), (5..=23).collect::<Vec<_>>());
+        // night wraps past midnight, both ends inclusive
+        assert_eq!(kept(&HourFilter::from(Preset::Night), None), [0, 1, 2, 3, 4, 5, 6, 18, 19, 20, 21, 22, 23]);
+    }
+
+    #[test]
+    fn parses_preset_names() {
+        assert_eq!(Preset::from_str("waking").unwrap(), Preset::Waking);
+        assert_eq!(Preset::from_str("night").unwrap(), Preset::Night);
+        assert!(Preset::from_str("Night").is_err());
+        assert!(Preset::from_str("").is_err());
+    }
+
+    #[test]
+    fn parses_hour_lists() {
+        assert_eq!(parse_hours("0, 3,21,").unwrap(), HashSet::from([0, 3, 21]));
+        assert_eq!(parse_hours("23").unwrap(), HashSet::from([23]));
+        assert_eq!(parse_hours("").unwrap(), HashSet::new());
+        assert!(parse_hours("24").is_err());
+        assert!(parse_hours("-1").is_err());
+        assert!(parse_hours("3;4").is_err());
+        assert!(parse_hours("9am").is_err());
+    }
+
+    #[test]
+    fn include_replaces_the_preset_and_exclude_wins() {
+        let filter = HourFilter::load(Some("daytime"), Some("0,6,12"), Some("12"), false).unwrap();
+
+        assert_eq!(kept(&filter, None), [0, 6]);
+        assert!(HourFilter::load(Some("noon"), None, None, false).is_err());
+        assert!(HourFilter::load(None, Some("25"), None, false).is_err());
+    }
+
+    #[test]
+    fn daylight_keeps_the_sunrise_to_sunset_window() {
+        let filter = HourFilter::from(Preset::All).daylight(true);
+
+        assert_eq!(kept(&filter, Some((5, 19))), (5..=19).collect::<Vec<_>>());
+        // a window wrapping midnight on a far away clock
+        assert_eq!(kept(&filter, Some((22, 2))), [0, 1, 2, 22, 23]);
+        // off without a window, and ignored when daylight mode is off
+        assert_eq!(kept(&filter, None).len(), 24);
+        assert_eq!(kept(&HourFilter::from(Preset::All), Some((5, 19))).len(), 24);
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+
+use crate::http::HttpClient;
+use crate::request::{ApiError, Location};
+
+// Module resolves place names into coordinates using the
+// OpenWeatherMap geocoding api, asking the user to choose
+// when a name is ambiguous e.g. Portland, OR vs Portland, ME.
+// Resolved coordinates are cached so later runs query by lat/lon,
+// and offline only those cached coordinates are used. Coordinates
+// are named with a reverse lookup, cached the same way.
+
+const BASE_GEO_URL: &str = "https://api.openweathermap.org/geo/1.0";
+const CANDIDATE_LIMIT: &str = "5";
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct GeoPlace {
+    name: String,
+    state: Option<String>,
+    country: String,
+    lat: f64,
+    lon: f64,
+}
+
+impl GeoPlace {
+    #[inline]
+    pub fn location(&self) -> Location {
+        Location::Coordinates(self.lat, self.lon)
+    }
+
+    // Name with the state where there is one e.g. "Portland, Oregon"
+    pub fn label(&self) -> String {
+        match self.state {
+            Some(ref state) => format!("{}, {}", self.name, state),
+            None => self.name.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+}
+
+impl fmt::Display for GeoPlace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.state {
+            Some(ref state) => write!(f, "{}, {}, {} [{}, {}]", self.name, state, self.country, self.lat, self.lon),
+            None => write!(f, "{}, {} [{}, {}]", self.name, self.country, self.lat, self.lon),
+        }
+    }
+}
+
+
+#[derive(Debug)]
+pub struct Geocoder {
+    api_key: String,
+    base_url: String,
+    cache: Option<PathBuf>,
+    offline: bool,
+    http: HttpClient,
+}
+
+impl Geocoder {
+    pub fn new(api_key: String) -> Geocoder {
+        Geocoder {
+            api_key,
+            base_url: BASE_GEO_URL.to_owned(),
+            cache: dirs::cache_dir().map(|d| d.join("weathr").join("locations.json")),
+            offline: false,
+            http: HttpClient::new(),
+        }
+    }
+
+    // Point at a different host e.g. a local stand-in server
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+
+    // Use a different cache file, None disables caching
+    pub fn with_cache(mut self, cache: Option<PathBuf>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    // Resolve names from the cache alone, never calling the api
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    pub fn with_http(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    // Candidate places matching a name based location
+    pub fn direct(&self, location: &Location) -> Result<Vec<GeoPlace>, ApiError> {
+        let mut url = self.url("direct")?;
+
+        url.query_pairs_mut()
+            .extend_pairs(location.query())
+            .append_pair("limit", CANDIDATE_LIMIT);
+
+        self.request(url)
+    }
+
+    // Candidate place names near the given coordinates
+    pub fn reverse(&self, lat: f64, lon: f64) -> Result<Vec<GeoPlace>, ApiError> {
+        let mut url = self.url("reverse")?;
+
+        url.query_pairs_mut()
+            .append_pair("lat", &lat.to_string())
+            .append_pair("lon", &lon.to_string())
+            .append_pair("limit", "1");
+
+        self.request(url)
+    }
+
+    // Nearest named place to the coordinates, None when there is none
+    // e.g. out at sea, or offline when it wasn't looked up before
+    pub fn name(&self, lat: f64, lon: f64) -> Result<Option<GeoPlace>, ApiError> {
+        let key = format!("{:.4},{:.4}", lat, lon);
+        let mut cache = self.load_cache();
+
+        if let Some(place) = cache.get(&key) {
+            return Ok(Some(place.clone()))
+        }
+
+        if self.offline {
+            return Ok(None)
+        }
+
+        let place = self.reverse(lat, lon)?.into_iter().next();
+
+        if let Some(ref place) = place {
+            cache.insert(key, place.clone());
+            self.save_cache(&cache);
+        }
+
+        Ok(place)
+    }
+
+    // Resolve name based locations into coordinates, other
+    // location types are already unambiguous and returned as is.
+    // pick selects a candidate (1-based) without prompting
+    pub fn resolve(&self, location: &Location, pick: Option<usize>) -> Result<Location, ApiError> {
+        match location {
+            Location::City(_) | Location::CityCountry(..) | Location::CityStateCountry(..) => (),
+            _ => return Ok(location.clone()),
+        }
+
+        let key = location.to_string().to_lowercase();
+        let mut cache = self.load_cache();
+
+        // an explicit pick re-resolves, unless offline
+        if let Some(place) = cache.get(&key).filter(|_| pick.is_none() || self.offline) {
+            return Ok(place.location())
+        }
+
+        if self.offline {
+            return Err(ApiError::NotCached(key))
+        }
+
+        let candidates = self.direct(location)?;
+
+        let place = match (candidates.len(), pick) {
+            (0, _) => return Err(ApiError::NoMatches(location.to_string())),
+            (1, None) => candidates[0].clone(),
+            (n, Some(p)) if p == 0 || p > n => return Err(ApiError::BadPick(p, n)),
+            (_, Some(p)) => candidates[p - 1].clone(),
+            (n, None) => candidates[prompt(&candidates).map_err(|_| ApiError::NoSelection(n))? - 1].clone(),
+        };
+
+        cache.insert(key, place.clone());
+        self.save_cache(&cache);
+
+        Ok(place.location())
+    }
+
+    fn url(&self, endpoint: &str) -> Result<Url, ApiError> {
+        let mut url = Url::parse(&self.base_url)?;
+
+        url.path_segments_mut()
+            .map_err(|_| ApiError::BadParse("geocoding url cannot be a base"))?
+            .push(endpoint);
+
+        url.query_pairs_mut().append_pair("appid", &self.api_key);
+
+        Ok(url)
+    }
+
+    // Places HTTP get (blocking) call, retried on transient failures
+    fn request(&self, url: Url) -> Result<Vec<GeoPlace>, ApiError> {
+        let response = self.http.call(self.http.get(url.as_str())).map_err(ApiError::decode)?.into_string()?;
+
+        serde_json::from_str(&response).map_err(|_| ApiError::BadParse("unexpected geocoding response"))
+    }
+
+    // A missing or unreadable cache is treated as empty
+    fn load_cache(&self) -> BTreeMap<String, GeoPlace> {
+        self.cache.as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    // Caching is best effort, failures to write are ignored
+    fn save_cache(&self, cache: &BTreeMap<String, GeoPlace>) {
+        if let Some(path) = self.cache.as_ref() {
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+
+            if let Ok(contents) = serde_json::to_string_pretty(cache) {
+                let _ = fs::write(path, contents);
+            }
+        }
+    }
+}
+
+// List candidates on stderr and read a 1-based choice from stdin,
+// failing straight away when there is no one at a terminal to answer
+fn prompt(candidates: &[GeoPlace]) -> Result<usize, io::Error> {
+    let mut stderr = io::stderr();
+
+    if !io::stdin().is_terminal() {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stdin is not a terminal"))
+    }
+
+    writeln!(stderr, "Multiple places match, choose one:")?;
+
+    for (i, place) in candidates.iter().enumerate() {
+        writeln!(stderr, "  {}) {}", i + 1, place)?;
+    }
+
+    loop {
+        write!(stderr, "Selection [1-{}]: ", candidates.len())?;
+        stderr.flush()?;
+
+        let mut line = String::new();
+        if io::stdin().lock().read_line(&mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "no selection made"))
+        }
+
+        match line.trim().parse::<usize>() {
+            Ok(n) if n >= 1 && n <= candidates.len() => return Ok(n),
+            _ => writeln!(stderr, "Invalid selection")?,
+        }
+    }
+}
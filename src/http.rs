@@ -0,0 +1,133 @@
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::DateTime;
+use ureq::{Agent, AgentBuilder, ErrorKind, Request, Response};
+
+use crate::request::ApiError;
+
+// Module wraps the ureq agent every web service call goes through, so
+// a hung connection times out rather than blocking forever and transient
+// failures (connection errors, 429 and 5xx) are retried. Retries back off
+// exponentially with jitter, waiting at least as long as any Retry-After.
+// A Retry-After beyond the longest backoff fails the call straight away.
+
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+pub const READ_TIMEOUT: Duration = Duration::from_secs(15);
+pub const RETRIES: u32 = 3;
+const BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Debug)]
+pub struct HttpClient {
+    agent: Agent,
+    retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for HttpClient {
+    fn default() -> Self { HttpClient::new() }
+}
+
+impl HttpClient {
+    pub fn new() -> HttpClient {
+        HttpClient {
+            agent: agent(CONNECT_TIMEOUT, READ_TIMEOUT),
+            retries: RETRIES,
+            backoff: BACKOFF,
+            max_backoff: MAX_BACKOFF,
+        }
+    }
+
+    // Limits on establishing a connection and on each read once connected
+    pub fn with_timeouts(mut self, connect: Duration, read: Duration) -> Self {
+        self.agent = agent(connect, read);
+        self
+    }
+
+    // Attempts made after the first fails, 0 disables retrying
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    // First retry delay, doubled each retry up to max
+    pub fn with_backoff(mut self, backoff: Duration, max: Duration) -> Self {
+        self.backoff = backoff;
+        self.max_backoff = max;
+        self
+    }
+
+    #[inline]
+    pub fn get(&self, url: &str) -> Request {
+        self.agent.get(url)
+    }
+
+    // Places the (blocking) request, retrying while failures look transient
+    pub fn call(&self, request: Request) -> Result<Response, ApiError> {
+        let mut attempt = 0;
+
+        loop {
+            match request.clone().call() {
+                Ok(response) => return Ok(response),
+                Err(e) if attempt < self.retries && is_transient(&e) => match self.delay(attempt, retry_after(&e)) {
+                    Some(delay) => {
+                        thread::sleep(delay);
+                        attempt += 1;
+                    },
+                    None => return Err(e.into()),
+                },
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    // Exponential backoff with jitter over its upper half, never shorter
+    // than the server's Retry-After. None when that is longer than max
+    fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        let backoff = self.backoff.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_backoff);
+        let half = backoff.as_millis() as u64 / 2;
+
+        // sub-second clock noise is random enough to spread out clients
+        let noise = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos() as u64).unwrap_or_default();
+        let jittered = Duration::from_millis(half + noise % (half + 1));
+
+        match retry_after {
+            Some(r) if r > self.max_backoff => None,
+            Some(r) => Some(r.max(jittered)),
+            None => Some(jittered),
+        }
+    }
+}
+
+fn agent(connect: Duration, read: Duration) -> Agent {
+    AgentBuilder::new()
+        .timeout_connect(connect)
+        .timeout_read(read)
+        .build()
+}
+
+fn is_transient(e: &ureq::Error) -> bool {
+    match e {
+        ureq::Error::Status(code, _) => matches!(code, 429 | 500 | 502 | 503 | 504),
+        ureq::Error::Transport(t) => matches!(t.kind(), ErrorKind::ConnectionFailed | ErrorKind::Io | ErrorKind::Dns),
+    }
+}
+
+// Retry-After given either in seconds or as an http date
+fn retry_after(e: &ureq::Error) -> Option<Duration> {
+    let value = match e {
+        ureq::Error::Status(_, response) => response.header("Retry-After")?,
+        ureq::Error::Transport(_) => return None,
+    };
+
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds))
+    }
+
+    let at = DateTime::parse_from_rfc2822(value).ok()?.timestamp();
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+
+    Some(Duration::from_secs((at - now).max(0) as u64))
+}
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+
+use crate::backend::{DayKey, Granularity};
+use crate::display::{Alert, Render, WeatherForecast, WeatherSection};
+use crate::request::{Location, Units};
+
+// Module renders the forecast as an iCalendar (RFC 5545) feed so it can
+// be subscribed to from calendar apps. Each day is an all-day event
+// summarizing the high, low and dominant condition with the sections
+// listed in its description, alerts are timed events of their own.
+// UIDs derive from the date (or alert) and coordinates rather than the
+// fetch time, so re-importing a newer feed updates the existing events.
+
+const PRODID: &str = concat!("-//weathr//weathr ", env!("CARGO_PKG_VERSION"), "//EN");
+const DOMAIN: &str = "weathr";
+// Longest content line in octets, excluding the line break
+const LINE_LIMIT: usize = 75;
+
+pub struct Ics;
+
+impl Render for Ics {
+    fn render(&self, forecast: &WeatherForecast) -> String {
+        let city = forecast.location();
+        let place = match city.country() {
+            "" => city.name().to_owned(),
+            country => format!("{}, {}", city.name(), country),
+        };
+        let site = Site::new(&city.location());
+        let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut lines = vec![
+            String::from("BEGIN:VCALENDAR"),
+            String::from("VERSION:2.0"),
+            format!("PRODID:{}", PRODID),
+            String::from("CALSCALE:GREGORIAN"),
+            String::from("METHOD:PUBLISH"),
+            format!("X-WR-CALNAME:{}", text(&format!("Weather {}", place))),
+        ];
+
+        for (day, sections) in forecast.days() {
+            let next = day.date().succ_opt().unwrap_or_else(|| day.date());
+
+            lines.push(String::from("BEGIN:VEVENT"));
+            lines.push(format!("UID:{}-{}@{}", day.date().format("%Y%m%d"), site.id, DOMAIN));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", day.date().format("%Y%m%d")));
+            lines.push(format!("DTEND;VALUE=DATE:{}", next.format("%Y%m%d")));
+            lines.push(format!("SUMMARY:{}", text(&summary(city.name(), sections, forecast.units()))));
+            lines.push(format!("DESCRIPTION:{}", text(&details(day, sections, forecast.granularity(), forecast.units()))));
+            lines.push(format!("LOCATION:{}", text(&place)));
+            lines.extend(site.geo.clone());
+            lines.push(String::from("TRANSP:TRANSPARENT"));
+            lines.push(String::from("END:VEVENT"));
+        }
+
+        for alert in forecast.alerts() {
+            lines.push(String::from("BEGIN:VEVENT"));
+            lines.push(format!("UID:alert-{}-{}-{}@{}", alert.start, slug(&alert.event), site.id, DOMAIN));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART:{}", utc(alert.start)));
+            lines.push(format!("DTEND:{}", utc(alert.end.max(alert.start))));
+            lines.push(format!("SUMMARY:{}", text(&format!("⚠ {}", alert.event))));
+            lines.push(format!("DESCRIPTION:{}", text(&warning(alert))));
+            lines.push(format!("LOCATION:{}", text(&place)));
+            lines.extend(site.geo.clone());
+            lines.push(String::from("END:VEVENT"));
+        }
+
+        lines.push(String::from("END:VCALENDAR"));
+        lines.iter().map(|l| format!("{}\r\n", fold(l))).collect()
+    }
+}
+
+// Coordinates as they appear in UIDs and the GEO property,
+// rounded so small geocoding drift keeps the same UIDs
+struct Site {
+    id: String,
+    geo: Option<String>,
+}
+
+impl Site {
+    fn new(location: &Location) -> Site {
+        match location {
+            Location::Coordinates(lat, lon) => Site {
+                id: format!("{:.2}_{:.2}", lat, lon),
+                geo: Some(format!("GEO:{:.4};{:.4}", lat, lon)),
+            },
+            other => Site { id: slug(&other.to_string()), geo: None },
+        }
+    }
+}
+
+// e.g. "Tucson: 97°/77°F clear sky"
+fn summary(name: &str, sections: &[WeatherSection], units: Units) -> String {
+    let high = sections.iter().map(|s| s.temp_max).fold(f32::MIN, f32::max);
+    let low = sections.iter().map(|s| s.temp_min).fold(f32::MAX, f32::min);
+
+    format!("{}: {:.0}°/{:.0}°{} {}", name, high, low, degrees(units), dominant(sections)).trim().to_owned()
+}
+
+// Most frequent primary condition, ties going to the earliest
+fn dominant(sections: &[WeatherSection]) -> &str {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let primary: Vec<&str> = sections.iter()
+        .filter_map(|s| s.conditions.first())
+        .map(|c| c.description.as_str())
+        .collect();
+
+    for description in &primary {
+        *counts.entry(description).or_default() += 1;
+    }
+
+    primary.iter()
+        .fold(None, |best: Option<&str>, d| match best {
+            Some(b) if counts[b] >= counts[d] => Some(b),
+            _ => Some(d),
+        })
+        .unwrap_or_default()
+}
+
+// One line per section e.g. "14:00 95°F clear sky, 0% precip, wind 9 mph"
+fn details(day: &DayKey, sections: &[WeatherSection], granularity: Granularity, units: Units) -> String {
+    let wind = match units {
+        Units::Metric => "m/s",
+        Units::Imperial => "mph",
+    };
+
+    let lines: Vec<String> = sections.iter()
+        .map(|s| {
+            let when = match granularity {
+                Granularity::Daily => day.to_string(),
+                _ => s.local.format("%H:%M").to_string(),
+            };
+
+            format!("{} {:.0}°{} {}, {:.0}% precip, wind {:.0} {}",
+                when, s.temp, degrees(units), s.joined(", ", |c| c.description.clone()), s.pop * 100.0, s.wind_speed, wind)
+        })
+        .collect();
+
+    lines.join("\n")
+}
+
+fn warning(alert: &Alert) -> String {
+    match alert.sender.as_str() {
+        "" => alert.description.clone(),
+        sender => format!("{}\n\n{}", sender, alert.description),
+    }
+}
+
+#[inline]
+fn degrees(units: Units) -> char {
+    match units {
+        Units::Metric => 'C',
+        Units::Imperial => 'F',
+    }
+}
+
+fn utc(timestamp: u64) -> String {
+    NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
+        .map(|t| t.format("%Y%m%dT%H%M%SZ").to_string())
+        .unwrap_or_default()
+}
+
+// Lowercase alphanumerics joined by dashes e.g. "heat-advisory"
+fn slug(s: &str) -> String {
+    s.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// TEXT values escape backslashes, separators and line breaks
+fn text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Long lines continue on lines starting with a space, split
+// between characters so multi-byte text stays intact
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / LINE_LIMIT * 3);
+    let mut width = 0;
+
+    for c in line.chars() {
+        if width + c.len_utf8() > LINE_LIMIT {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+
+        folded.push(c);
+        width += c.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{Clock, WeatherList};
+    use crate::filter::{HourFilter, Preset};
+    use crate::mock_json::JSON_RESPONSE;
+    use crate::output::Output;
+    use crate::request::ApiError;
+
+    fn forecast() -> WeatherForecast {
+        WeatherList::parse(JSON_RESPONSE).unwrap()
+            .transform(Clock::City, &HourFilter::from(Preset::All))
+            .unwrap()
+            .with_alerts(vec![Alert {
+                sender: String::from("Météo-France"),
+                event: String::from("Canicule; vigilance orange"),
+                start: 1655571600,
+                end: 1655604000,
+                starts: String::new(),
+                ends: String::new(),
+                description: String::from("Températures élevées à très élevées, jusqu'à 40 °C l'après-midi, \
+                                           nuits tropicales à 25 °C. Évitez les efforts aux heures chaudes."),
+                tags: Vec::new(),
+            }])
+    }
+
+    fn uids(calendar: &str) -> Vec<&str> {
+        calendar.lines().filter(|l| l.starts_with("UID:")).collect()
+    }
+
+    #[test]
+    fn folds_at_75_octets_between_characters() {
+        let line = format!("DESCRIPTION:{}", "é⚠".repeat(40));
+        let folded = fold(&line);
+
+        assert!(folded.split("\r\n").all(|l| l.len() <= LINE_LIMIT));
+        assert!(folded.split("\r\n").skip(1).all(|l| l.starts_with(' ')));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+        assert_eq!(fold("SUMMARY:short"), "SUMMARY:short");
+    }
+
+    #[test]
+    fn escapes_text_values() {
+        assert_eq!(text("a;b,c\\d\ne\r\nf"), "a\\;b\\,c\\\\d\\ne\\nf");
+    }
+
+    #[test]
+    fn renders_a_day_per_event_and_each_alert() {
+        let calendar = Ics.render(&forecast());
+        let unfolded = calendar.replace("\r\n ", "");
+
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n") && calendar.ends_with("END:VCALENDAR\r\n"));
+        assert!(calendar.split("\r\n").all(|l| l.len() <= LINE_LIMIT));
+        // six days of the 5 day forecast on the city's clock, then the alert
+        assert_eq!(unfolded.matches("BEGIN:VEVENT").count(), 7);
+        assert!(unfolded.contains("\r\nUID:20220618-32.22_-110.93@weathr\r\n"));
+        assert!(unfolded.contains("\r\nLOCATION:Tucson\\, US\r\n"));
+        assert!(unfolded.contains("\r\nSUMMARY:⚠ Canicule\\; vigilance orange\r\n"));
+        assert!(unfolded.contains("\r\nDESCRIPTION:Météo-France\\n\\nTempératures élevées à très élevées\\, jusqu'à 40 °C"));
+        assert!(unfolded.contains("\r\nDTSTART:20220618T170000Z\r\n"));
+    }
+
+    // UID of each day's event by its start date
+    fn day_uids(calendar: &str) -> HashMap<&str, &str> {
+        let lines: Vec<&str> = calendar.lines().collect();
+
+        lines.windows(3)
+            .filter_map(|w| Some((w[2].strip_prefix("DTSTART;VALUE=DATE:")?, w[0].strip_prefix("UID:")?)))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_uids_across_renders() {
+        let first = Ics.render(&forecast());
+        // a later fetch on another clock, the sections fall into different days and slots
+        let moved = WeatherList::parse(JSON_RESPONSE).unwrap()
+            .transform(Clock::Utc, &HourFilter::from(Preset::Daytime))
+            .unwrap();
+        let second = Ics.render(&moved);
+        let (before, after) = (day_uids(&first), day_uids(&second));
+
+        assert_eq!(uids(&first).len(), 7);
+        assert!(uids(&first).contains(&"UID:alert-1655571600-canicule-vigilance-orange-32.22_-110.93@weathr"));
+        assert_ne!(first.lines().filter(|l| l.starts_with("DESCRIPTION:")).collect::<Vec<_>>(),
+                   second.lines().filter(|l| l.starts_with("DESCRIPTION:")).collect::<Vec<_>>());
+
+        let shared: Vec<&&str> = before.keys().filter(|date| after.contains_key(*date)).collect();
+        assert!(shared.len() >= 5, "{:?}", shared);
+        assert!(shared.iter().all(|date| before[**date] == after[**date]));
+        assert_eq!(after["20220619"], "20220619-32.22_-110.93@weathr");
+    }
+
+    #[test]
+    fn rejects_minutely_forecasts() {
+        let minutely = forecast().with_granularity(Granularity::Minutely);
+
+        assert!(matches!(Output::Ics.render(&minutely), Err(ApiError::Unsupported("ics output", _))));
+        assert!(Output::Csv.render(&minutely).is_ok());
+    }
+}
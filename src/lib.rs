@@ -3,3 +3,17 @@ pub mod mock_json;
 pub mod display;
 pub mod request;
 pub mod config;
+pub mod filter;
+pub mod geocode;
+pub mod onecall;
+pub mod provider;
+pub mod openweathermap;
+pub mod openmeteo;
+pub mod nws;
+pub mod metno;
+pub mod ensemble;
+pub mod failover;
+pub mod cache;
+pub mod http;
+pub mod output;
+pub mod ics;
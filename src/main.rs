@@ -1,34 +1,176 @@
-﻿use std::env;
+use std::env;
 use std::error::Error;
+use std::fs;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use dotenv::dotenv;
 
+use weathr::cache::{CacheMode, ResponseCache};
 use weathr::config::WeatherConfig;
-use weathr::request::WeatherApi;
-use weathr::backend::WeatherList;
-use weathr::display::WeatherForecast;
+use weathr::http::{HttpClient, CONNECT_TIMEOUT, READ_TIMEOUT, RETRIES};
+use weathr::request::{ApiError, Location, Units};
+use weathr::backend::{Clock, Granularity};
+use weathr::display::{ColumnGroup, Columns, WeatherForecast};
+use weathr::filter::HourFilter;
+use weathr::output::Output;
+use weathr::ensemble::Ensemble;
+use weathr::failover::Failover;
+use weathr::provider::{Provider, Query, WeatherProvider};
 
-fn main() -> Result<(), Box<dyn Error>> {
+// Exit status signalling an active weather alert to calling scripts
+const ALERT_EXIT_CODE: i32 = 3;
+
+fn main() {
+    // Errors are reported with what to do about them, and exit
+    // with a status telling scripts which kind of failure it was
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+
+        let api = e.downcast_ref::<ApiError>();
+
+        if let Some(hint) = api.and_then(ApiError::hint) {
+            eprintln!("{}", hint);
+        }
+
+        process::exit(api.map_or(1, ApiError::exit_code));
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
 
     // Use environment variable as source of api key via export WEATHER_API_KEY="mykey"
     // 12 factor app - best practices "store config in environment"
     dotenv().ok();
 
-    let api_key: String = env::var("WEATHER_API_KEY").expect("export WEATHER_API_KEY= not set");
+    let api_key: Option<String> = env::var("WEATHER_API_KEY").ok();
 
     // Retrieve user supplied config portion
     let config = WeatherConfig::load();
 
-    // Construct api request
-    let api = WeatherApi::load(api_key, config.location(), config.units())?;
+    // Responses kept on disk between runs
+    let mode = match (config.offline(), config.refresh()) {
+        (true, _) => CacheMode::Offline,
+        (_, true) => CacheMode::Refresh,
+        _ => CacheMode::Normal,
+    };
+
+    let cache = ResponseCache::new()
+        .with_ttl(config.cache_ttl().map(|minutes| minutes * 60))
+        .with_mode(mode);
+
+    // Timeouts and retries for every web service call
+    let http = HttpClient::new()
+        .with_timeouts(
+            config.connect_timeout().map_or(CONNECT_TIMEOUT, Duration::from_secs),
+            config.timeout().map_or(READ_TIMEOUT, Duration::from_secs),
+        )
+        .with_retries(config.retries().unwrap_or(RETRIES));
+
+    // Web service backing the forecast, or several combined
+    let provider: Box<dyn WeatherProvider> = match config.ensemble() {
+        Some(list) => {
+            let members = Provider::list(list)?.iter()
+                .map(|p| p.build(api_key.as_deref(), &cache, &http))
+                .collect::<Result<_, _>>()?;
+
+            Box::new(Ensemble::new(members))
+        },
+        None => {
+            let primary = config.provider().map(Provider::from_str).transpose()?
+                .unwrap_or_else(|| Provider::default_for(api_key.as_deref()));
+
+            // providers tried in turn should the primary be down
+            let fallback = match config.fallback() {
+                _ if mode == CacheMode::Offline => Vec::new(),
+                Some("none") => Vec::new(),
+                Some(list) => Provider::list(list)?,
+                None => vec![Provider::OpenMeteo],
+            };
+
+            let mut chain = vec![primary.build(api_key.as_deref(), &cache, &http)?];
+
+            for p in fallback.into_iter().filter(|p| *p != primary) {
+                chain.push(p.build(api_key.as_deref(), &cache, &http)?);
+            }
+
+            match chain.len() {
+                1 => chain.remove(0),
+                _ => Box::new(Failover::new(chain)),
+            }
+        },
+    };
+
+    // Construct provider query
+    let location = Location::from_str(config.location())?;
+    let units = config.units().and_then(|u| Units::from_str(u).ok()).unwrap_or_default();
+    let mut query = Query::new(location, units);
+
+    // Clock which days and hours are grouped by
+    query.clock = config.tz().map(Clock::from_str).transpose()?.unwrap_or_default();
 
-    let response = api.request()?;
+    // Hours kept per day
+    query.filter = HourFilter::load(config.hours(), config.include_hours(), config.exclude_hours(), config.daylight())?;
 
-    // Parse then normalize data before displaying
-    let data: WeatherList = WeatherList::parse(&response)?;
-    let forecast: WeatherForecast = data.into();
+    query.granularity = config.granularity().map(Granularity::from_str).transpose()?.unwrap_or_default();
+    query.alerts = config.alerts();
+    query.pick = config.pick();
 
-    println!("{}", forecast);
+    // Optional column groups shown in the table
+    let mut columns = Columns::load(config.columns())?;
+
+    if config.air() {
+        columns = columns.with(ColumnGroup::Air);
+    }
+
+    query.air = columns.contains(ColumnGroup::Air);
+
+    let output = config.output().map(Output::from_str).transpose()?.unwrap_or_default();
+    output.supports(query.granularity)?;
+
+    if config.now() {
+        if output != Output::Tabular {
+            return Err(ApiError::Unsupported("--now", "output types other than tabular").into())
+        }
+
+        let current = provider.current(&query)?;
+        return emit(config.out_file(), || Ok(current.to_string()))
+    }
+
+    // Fetch then normalize data before displaying
+    let forecast: WeatherForecast = provider.forecast(&query)?
+        .with_units(query.units)
+        .with_columns(columns);
+
+    emit(config.out_file(), || output.render(&forecast))?;
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+    if forecast.has_active_alert(now) {
+        process::exit(ALERT_EXIT_CODE);
+    }
 
     Ok(())
 }
 
+// Print to the terminal or write to a file, which gets plain
+// text as colors only make sense in the terminal. Output is
+// newline terminated unless it already ends its own lines
+fn emit(path: Option<&str>, render: impl FnOnce() -> Result<String, ApiError>) -> Result<(), Box<dyn Error>> {
+    if path.is_some() {
+        colored::control::set_override(false);
+    }
+
+    let mut text = render()?;
+
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+
+    match path {
+        Some(path) => fs::write(path, text)?,
+        None => print!("{}", text),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,505 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::backend::{estimate_daylight, group_days, three_hourly, City, Clock, Condition, DayKey, Granularity, Precipitation, Span};
+use crate::display::{CurrentConditions, WeatherForecast, WeatherSection};
+use crate::http::HttpClient;
+use crate::openmeteo::{locate, timezone, Place, GEOCODING_URL, TIMEZONE_URL};
+use crate::provider::{Query, WeatherProvider};
+use crate::request::{ApiError, Location, Units, USER_AGENT};
+
+// MET Norway provider (Locationforecast 2.0 compact), free and keyless.
+// Data is hourly for the first days then every 6 hours, always in
+// metric units and UTC. The terms of service require an identifying
+// User-Agent, coordinates of at most 4 decimals, and that clients
+// honor Expires and re-validate with If-Modified-Since, so each
+// response is kept on disk alongside those headers.
+
+/*
+   Snippet of compact JSON data
+
+   properties: {
+     meta: { updated_at: "2022-06-18T23:11:02Z", units: { air_temperature: "celsius", ... } },
+     timeseries: [{
+       time: "2022-06-19T00:00:00Z",
+       data: {
+         instant: { details: {
+           air_pressure_at_sea_level: 1011.3, air_temperature: 33.6, cloud_area_fraction: 75.0,
+           relative_humidity: 30.1, wind_from_direction: 236.0, wind_speed: 9.6
+         }},
+         next_1_hours: { summary: { symbol_code: "lightrain" }, details: { precipitation_amount: 0.2 } },
+         next_6_hours: { summary: { symbol_code: "rain" }, details: { precipitation_amount: 0.8 } },
+         next_12_hours: { summary: { symbol_code: "partlycloudy_night" } }
+       }
+     }, ...]
+   }
+*/
+
+const BASE_API_URL: &str = "https://api.met.no/weatherapi/locationforecast/2.0/compact";
+
+#[derive(Debug)]
+pub struct MetNo {
+    base_url: String,
+    geocoding_url: String,
+    timezone_url: String,
+    cache: Option<PathBuf>,
+    http: HttpClient,
+}
+
+impl Default for MetNo {
+    fn default() -> Self { MetNo::new() }
+}
+
+impl MetNo {
+    pub fn new() -> MetNo {
+        MetNo {
+            base_url: BASE_API_URL.to_owned(),
+            geocoding_url: GEOCODING_URL.to_owned(),
+            timezone_url: TIMEZONE_URL.to_owned(),
+            cache: dirs::cache_dir().map(|d| d.join("weathr").join("metno")),
+            http: HttpClient::new(),
+        }
+    }
+
+    // Point at different hosts e.g. a local stand-in server
+    pub fn with_base_url(mut self, base_url: &str, geocoding_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self.geocoding_url = geocoding_url.to_owned();
+        self
+    }
+
+    // Timezone lookup for coordinates, the Open-Meteo forecast api by default
+    pub fn with_timezone_url(mut self, timezone_url: &str) -> Self {
+        self.timezone_url = timezone_url.to_owned();
+        self
+    }
+
+    // Use a different cache directory, None disables conditional requests
+    pub fn with_cache(mut self, cache: Option<PathBuf>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn with_http(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    // MET Norway has no geocoder, place names are resolved with Open-Meteo's
+    fn place(&self, query: &Query) -> Result<Place, ApiError> {
+        match query.location {
+            Location::Zip(..) | Location::Id(_) => Err(ApiError::Unsupported("met.no", "zip or city id locations")),
+            _ => locate(&self.http, &self.geocoding_url, query),
+        }
+    }
+
+    // A cached response is reused until it expires, then re-validated
+    // with If-Modified-Since where a 304 means it is still current.
+    // Data is in UTC, so places without a timezone (coordinates) have it
+    // looked up for days to split at local midnight, and kept with the
+    // response as it will not change
+    fn request(&self, place: &mut Place) -> Result<Compact, ApiError> {
+        let (lat, lon) = (format!("{:.4}", place.latitude), format!("{:.4}", place.longitude));
+        let path = self.cache.as_ref().map(|dir| dir.join(format!("{},{}.json", lat, lon)));
+        let cached: Option<Cached> = path.as_ref()
+            .and_then(|p| fs::read_to_string(p).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+
+        if place.timezone.is_none() {
+            place.timezone = match cached.as_ref().and_then(|c| c.timezone.clone()) {
+                Some(tz) => Some(tz),
+                None => timezone(&self.http, &self.timezone_url, place.latitude, place.longitude)?,
+            };
+        }
+
+        if let Some(c) = cached.as_ref().filter(|c| c.is_fresh(Utc::now().timestamp())) {
+            return Ok(Compact::parse(&c.body)?)
+        }
+
+        let mut url = Url::parse(&self.base_url)?;
+        url.query_pairs_mut()
+            .append_pair("lat", &lat)
+            .append_pair("lon", &lon);
+
+        let mut request = self.http.get(url.as_str()).set("User-Agent", USER_AGENT);
+
+        if let Some(since) = cached.as_ref().and_then(|c| c.last_modified.as_ref()) {
+            request = request.set("If-Modified-Since", since);
+        }
+
+        let response = self.http.call(request)?;
+        let expires = response.header("Expires").and_then(|e| DateTime::parse_from_rfc2822(e).ok()).map(|e| e.timestamp());
+        let last_modified = response.header("Last-Modified").map(str::to_owned);
+
+        let fresh = match (response.status(), cached) {
+            (304, Some(c)) => Cached { expires, last_modified: last_modified.or(c.last_modified), timezone: place.timezone.clone(), body: c.body },
+            _ => Cached { expires, last_modified, timezone: place.timezone.clone(), body: response.into_string()? },
+        };
+
+        // caching is best effort, failures to write are ignored
+        if let Some(path) = path {
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+
+            if let Ok(contents) = serde_json::to_string(&fresh) {
+                let _ = fs::write(path, contents);
+            }
+        }
+
+        Ok(Compact::parse(&fresh.body)?)
+    }
+}
+
+impl WeatherProvider for MetNo {
+    fn name(&self) -> &'static str {
+        "met.no"
+    }
+
+    fn current(&self, query: &Query) -> Result<CurrentConditions, ApiError> {
+        let mut place = self.place(query)?;
+
+        self.request(&mut place)?.current(&place, query.units, query.clock)
+    }
+
+    fn forecast(&self, query: &Query) -> Result<WeatherForecast, ApiError> {
+        let mut place = self.place(query)?;
+        let data = self.request(&mut place)?;
+        let current = data.current(&place, query.units, query.clock)?;
+
+        Ok(data.transform(&place, query)?.with_current(current))
+    }
+}
+
+// Response body kept with the headers governing its reuse, and the
+// timezone of its place
+#[derive(Deserialize, Serialize, Debug)]
+struct Cached {
+    expires: Option<i64>,
+    last_modified: Option<String>,
+    #[serde(default)]
+    timezone: Option<String>,
+    body: String,
+}
+
+impl Cached {
+    #[inline]
+    fn is_fresh(&self, now: i64) -> bool {
+        self.expires.is_some_and(|expires| now < expires)
+    }
+}
+
+
+#[derive(Deserialize, Debug)]
+pub struct Compact {
+    properties: Properties,
+}
+
+#[derive(Deserialize, Debug)]
+struct Properties {
+    timeseries: Vec<TimeStep>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TimeStep {
+    time: String,
+    data: StepData,
+}
+
+#[derive(Deserialize, Debug)]
+struct StepData {
+    instant: Instant,
+    next_1_hours: Option<Period>,
+    next_6_hours: Option<Period>,
+    next_12_hours: Option<Period>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Instant {
+    details: InstantDetails,
+}
+
+#[derive(Deserialize, Debug)]
+struct InstantDetails {
+    air_pressure_at_sea_level: Option<f32>,
+    air_temperature: f32,
+    cloud_area_fraction: Option<f32>,
+    relative_humidity: Option<f32>,
+    wind_from_direction: Option<f32>,
+    wind_speed: Option<f32>,
+}
+
+// Forecast over the following 1, 6 or 12 hours
+#[derive(Deserialize, Debug)]
+struct Period {
+    summary: Summary,
+    details: Option<PeriodDetails>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Summary {
+    symbol_code: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct PeriodDetails {
+    precipitation_amount: Option<f32>,
+}
+
+impl Compact {
+    #[inline]
+    pub fn parse(response: &str) -> Result<Compact, serde_json::Error> {
+        serde_json::from_str(response)
+    }
+
+    // Utc offset of the place, UTC when its timezone is unknown
+    fn offset(&self, place: &Place) -> i32 {
+        let tz = place.timezone.as_ref().and_then(|tz| tz.parse::<Tz>().ok());
+        let value = self.properties.timeseries.first().and_then(|s| s.timestamp().ok()).unwrap_or_default();
+
+        tz.map(|tz| Clock::Zone(tz).localize(value, 0).offset().local_minus_utc()).unwrap_or_default()
+    }
+
+    // Header details, sunrise and sunset are estimated from
+    // the day and night variants of the hourly symbols
+    fn city(&self, place: &Place) -> City {
+        let mut daytime = true;
+        let hours = self.properties.timeseries.iter()
+            .filter(|s| s.data.next_1_hours.is_some())
+            .filter_map(|s| {
+                daytime = s.daytime().unwrap_or(daytime);
+                s.timestamp().ok().map(|ts| (ts, daytime))
+            });
+
+        let (sunrise, sunset) = estimate_daylight(hours);
+
+        City::new(
+            place.name.clone(),
+            place.country_code.clone().unwrap_or_default(),
+            place.latitude,
+            place.longitude,
+            sunrise,
+            sunset,
+            self.offset(place),
+        )
+    }
+
+    // The first time step covers the current hour
+    fn current(&self, place: &Place, units: Units, clock: Clock) -> Result<CurrentConditions, ApiError> {
+        let step = self.properties.timeseries.first().ok_or(ApiError::NoConditions("empty timeseries".to_owned()))?;
+        let (_, _, section) = step.transform(&clock, self.offset(place), units)?;
+        let today = self.properties.timeseries.iter().take(24).map(|s| convert_temp(s.data.instant.details.air_temperature, units));
+
+        Ok(CurrentConditions {
+            place: format!("{} {}", place.name, place.country_code.as_deref().unwrap_or_default()).trim().to_owned(),
+            datetime: clock.localize(section.timestamp, self.offset(place)).format("%a %m-%d %H:%M").to_string(),
+            temp: section.temp,
+            feels_like: section.feels_like,
+            temp_min: today.clone().fold(f32::MAX, f32::min),
+            temp_max: today.fold(f32::MIN, f32::max),
+            pressure: section.pressure,
+            humidity: section.humidity,
+            conditions: section.conditions,
+            clouds: section.clouds,
+            visibility: None,
+            wind_speed: section.wind_speed,
+            wind_deg: section.wind_deg,
+            wind_gust: None,
+            rain_one_hour: section.rain_volume,
+            snow_one_hour: section.snow_volume,
+            air_quality: None,
+        })
+    }
+
+    // transforms time steps into weather forecast, hourly uses the
+    // hourly steps, 3h rolls them up and daily folds each day together
+    fn transform(&self, place: &Place, query: &Query) -> Result<WeatherForecast, ApiError> {
+        let clock = query.clock;
+        let city = self.city(place);
+        let offset = self.offset(place);
+        let steps = self.properties.timeseries.iter();
+
+        let (list, filter) = match query.granularity {
+            Granularity::Minutely => return Err(ApiError::Unsupported("met.no", "minutely forecasts")),
+            Granularity::Hourly => (steps
+                .filter(|s| s.data.next_1_hours.is_some())
+                .map(|s| s.transform(&clock, offset, query.units))
+                .collect::<Result<Vec<_>, _>>()?, Some(&query.filter)),
+            Granularity::ThreeHour => (three_hourly(steps
+                .map(|s| s.transform(&clock, offset, query.units).map(|(day, hour, ws)| (day, hour, ws, s.span())))
+                .collect::<Result<Vec<_>, _>>()?), Some(&query.filter)),
+            Granularity::Daily => (daily(steps.map(|s| s.transform(&clock, offset, query.units)).collect::<Result<Vec<_>, _>>()?), None),
+        };
+
+        let map = group_days(list, filter, city.daylight_hours(&clock));
+
+        Ok(WeatherForecast::new(city, clock, map).with_granularity(query.granularity))
+    }
+}
+
+impl TimeStep {
+    #[inline]
+    fn timestamp(&self) -> Result<u64, ApiError> {
+        DateTime::parse_from_rfc3339(&self.time)
+            .map(|t| t.timestamp() as u64)
+            .map_err(|_| ApiError::BadParse("unexpected time step"))
+    }
+
+    // Shortest period ahead, hourly steps carry next_1_hours,
+    // later 6 hourly steps only the longer periods
+    fn period(&self) -> Option<&Period> {
+        self.data.next_1_hours.as_ref()
+            .or(self.data.next_6_hours.as_ref())
+            .or(self.data.next_12_hours.as_ref())
+    }
+
+    // Time the step's precipitation amount falls over, the hour
+    // or 6 hours ahead. Amounts for 12 hours aren't given
+    fn span(&self) -> Span {
+        let start = self.timestamp().unwrap_or_default();
+
+        match (&self.data.next_1_hours, &self.data.next_6_hours) {
+            (Some(_), _) => (start, start + 3600),
+            (None, Some(_)) => (start, start + 6 * 3600),
+            (None, None) => (start, start),
+        }
+    }
+
+    // Symbols for clear to partly cloudy skies end in _day or _night
+    fn daytime(&self) -> Option<bool> {
+        let symbol = &self.period()?.summary.symbol_code;
+
+        match symbol.rsplit_once('_') {
+            Some((_, "night")) | Some((_, "polartwilight")) => Some(false),
+            Some((_, "day")) => Some(true),
+            _ => None,
+        }
+    }
+
+    fn transform(&self, clock: &Clock, city_offset: i32, units: Units) -> Result<(DayKey, u8, WeatherSection), ApiError> {
+        let (day_key, mut section) = WeatherSection::at(clock, self.timestamp()?, city_offset);
+        let details = &self.data.instant.details;
+        let daytime = self.daytime().unwrap_or((6..=18).contains(&section.hour));
+        let condition = self.period().map(|p| metno_condition(&p.summary.symbol_code, daytime))
+            .ok_or_else(|| ApiError::NoConditions(section.dt_txt.clone()))?;
+        let amount = self.period().and_then(|p| p.details.as_ref()).and_then(|d| d.precipitation_amount).unwrap_or_default();
+        let precip_type = Precipitation::detect(condition.id, None, None);
+        let temp = convert_temp(details.air_temperature, units);
+
+        section.temp = temp;
+        section.feels_like = temp;
+        section.temp_min = temp;
+        section.temp_max = temp;
+        section.pressure = details.air_pressure_at_sea_level.unwrap_or_default().round() as u16;
+        section.humidity = details.relative_humidity.unwrap_or_default().round() as u16;
+        section.clouds = details.cloud_area_fraction.unwrap_or_default().round() as u8;
+        section.wind_speed = convert_speed(details.wind_speed.unwrap_or_default(), units);
+        section.wind_deg = details.wind_from_direction.unwrap_or_default().round() as u16;
+        section.rain_volume = Some(amount).filter(|a| *a > 0.0 && precip_type != Precipitation::Snow);
+        section.snow_volume = Some(amount).filter(|a| *a > 0.0 && precip_type == Precipitation::Snow);
+        section.precipitation = amount;
+        section.accumulation = Some(self.span()).map(|(start, end)| end - start).filter(|s| *s > 0);
+        section.precip_type = precip_type;
+        section.daytime = daytime;
+        section.conditions = vec![condition];
+
+        Ok((day_key, section.hour, section))
+    }
+}
+
+// Folds each day's steps into one section, the range spans every
+// step while conditions come from the step nearest midday
+fn daily(list: Vec<(DayKey, u8, WeatherSection)>) -> Vec<(DayKey, u8, WeatherSection)> {
+    let mut days: BTreeMap<DayKey, Vec<WeatherSection>> = BTreeMap::new();
+
+    for (day_key, _, section) in list {
+        days.entry(day_key).or_default().push(section);
+    }
+
+    days.into_iter().filter_map(|(day_key, sections)| {
+        let min = sections.iter().map(|s| s.temp).fold(f32::MAX, f32::min);
+        let max = sections.iter().map(|s| s.temp).fold(f32::MIN, f32::max);
+
+        // amounts cover the gap to the next step, 1 hour early on then 6
+        let precipitation: f32 = sections.iter().map(|s| s.precipitation).sum();
+
+        let mut section = sections.into_iter().min_by_key(|s| (s.hour as i8 - 12).abs())?;
+
+        let snow = section.precip_type == Precipitation::Snow;
+
+        section.temp = max;
+        section.feels_like = max;
+        section.temp_min = min;
+        section.temp_max = max;
+        section.daytime = true;
+        section.precipitation = precipitation;
+        section.accumulation = Some(24 * 3600);
+        section.rain_volume = Some(precipitation).filter(|p| *p > 0.0 && !snow);
+        section.snow_volume = Some(precipitation).filter(|p| *p > 0.0 && snow);
+
+        Some((day_key, section.hour, section))
+    }).collect()
+}
+
+// MET Norway always reports celsius and m/s
+#[inline]
+fn convert_temp(celsius: f32, units: Units) -> f32 {
+    match units {
+        Units::Imperial => celsius * 9.0 / 5.0 + 32.0,
+        Units::Metric => celsius,
+    }
+}
+
+#[inline]
+fn convert_speed(metres: f32, units: Units) -> f32 {
+    match units {
+        Units::Imperial => metres * 2.23694,
+        Units::Metric => metres,
+    }
+}
+
+// Maps a MET Norway symbol code onto the closest
+// OpenWeatherMap condition id, group, description and icon
+fn metno_condition(symbol: &str, daytime: bool) -> Condition {
+    let base = symbol.split('_').next().unwrap_or_default();
+    let thunder = base.ends_with("andthunder");
+    let weather = base.trim_end_matches("andthunder");
+
+    let (id, group, description, icon) = match weather {
+        _ if thunder => (211, "Thunderstorm", "thunderstorm", "11"),
+        "clearsky" => (800, "Clear", "clear sky", "01"),
+        "fair" => (801, "Clouds", "fair", "02"),
+        "partlycloudy" => (802, "Clouds", "partly cloudy", "03"),
+        "cloudy" => (804, "Clouds", "cloudy", "04"),
+        "fog" => (741, "Fog", "fog", "50"),
+        "lightrain" => (500, "Rain", "light rain", "10"),
+        "rain" => (501, "Rain", "rain", "10"),
+        "heavyrain" => (502, "Rain", "heavy rain", "10"),
+        "lightrainshowers" => (520, "Rain", "light rain showers", "09"),
+        "rainshowers" => (521, "Rain", "rain showers", "09"),
+        "heavyrainshowers" => (522, "Rain", "heavy rain showers", "09"),
+        "lightsleet" | "lightsleetshowers" => (612, "Snow", "light sleet", "13"),
+        "sleet" | "sleetshowers" => (611, "Snow", "sleet", "13"),
+        "heavysleet" | "heavysleetshowers" => (613, "Snow", "heavy sleet", "13"),
+        "lightsnow" => (600, "Snow", "light snow", "13"),
+        "snow" => (601, "Snow", "snow", "13"),
+        "heavysnow" => (602, "Snow", "heavy snow", "13"),
+        "lightsnowshowers" => (620, "Snow", "light snow showers", "13"),
+        "snowshowers" => (621, "Snow", "snow showers", "13"),
+        "heavysnowshowers" => (622, "Snow", "heavy snow showers", "13"),
+        _ => (800, "Unknown", "unknown", "01"),
+    };
+
+    Condition {
+        id,
+        group: group.to_owned(),
+        description: description.to_owned(),
+        icon: format!("{}{}", icon, if daytime { "d" } else { "n" }),
+    }
+}
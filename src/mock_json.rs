@@ -9,3 +9,33 @@ pub const JSON_RESPONSE_SMALL: &str = "{\"cod\":\"200\",\"message\":0,\"cnt\":40
 
 
 pub const JSON_RESPONSE: &str = "{\"cod\":\"200\",\"message\":0,\"cnt\":40,\"list\":[{\"dt\":1655586000,\"main\":{\"temp\":92.41,\"feels_like\":90.88,\"temp_min\":91.06,\"temp_max\":92.41,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":927,\"humidity\":30,\"temp_kf\":0.75},\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"}],\"clouds\":{\"all\":75},\"wind\":{\"speed\":21.43,\"deg\":236,\"gust\":21.74},\"visibility\":10000,\"pop\":0.45,\"rain\":{\"3h\":0.22},\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-18 21:00:00\"},{\"dt\":1655596800,\"main\":{\"temp\":93.34,\"feels_like\":90.72,\"temp_min\":93.34,\"temp_max\":93.49,\"pressure\":1009,\"sea_level\":1009,\"grnd_level\":926,\"humidity\":25,\"temp_kf\":-0.08},\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04d\"}],\"clouds\":{\"all\":66},\"wind\":{\"speed\":13.58,\"deg\":248,\"gust\":15.82},\"visibility\":10000,\"pop\":0.42,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-19 00:00:00\"},{\"dt\":1655607600,\"main\":{\"temp\":90.27,\"feels_like\":87.26,\"temp_min\":90.27,\"temp_max\":90.27,\"pressure\":1008,\"sea_level\":1008,\"grnd_level\":927,\"humidity\":25,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03n\"}],\"clouds\":{\"all\":29},\"wind\":{\"speed\":9.71,\"deg\":276,\"gust\":11.23},\"visibility\":10000,\"pop\":0.21,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-19 03:00:00\"},{\"dt\":1655618400,\"main\":{\"temp\":81.09,\"feels_like\":81.23,\"temp_min\":81.09,\"temp_max\":81.09,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":929,\"humidity\":45,\"temp_kf\":0},\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10n\"}],\"clouds\":{\"all\":19},\"wind\":{\"speed\":9.69,\"deg\":112,\"gust\":11.36},\"visibility\":10000,\"pop\":0.42,\"rain\":{\"3h\":0.72},\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-19 06:00:00\"},{\"dt\":1655629200,\"main\":{\"temp\":78.12,\"feels_like\":78.21,\"temp_min\":78.12,\"temp_max\":78.12,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":928,\"humidity\":55,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01n\"}],\"clouds\":{\"all\":6},\"wind\":{\"speed\":9.42,\"deg\":103,\"gust\":13.06},\"visibility\":10000,\"pop\":0.02,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-19 09:00:00\"},{\"dt\":1655640000,\"main\":{\"temp\":77.49,\"feels_like\":77.43,\"temp_min\":77.49,\"temp_max\":77.49,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":928,\"humidity\":53,\"temp_kf\":0},\"weather\":[{\"id\":801,\"main\":\"Clouds\",\"description\":\"few clouds\",\"icon\":\"02n\"}],\"clouds\":{\"all\":11},\"wind\":{\"speed\":7.16,\"deg\":131,\"gust\":8.88},\"visibility\":10000,\"pop\":0.01,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-19 12:00:00\"},{\"dt\":1655650800,\"main\":{\"temp\":83.03,\"feels_like\":81.86,\"temp_min\":83.03,\"temp_max\":83.03,\"pressure\":1013,\"sea_level\":1013,\"grnd_level\":930,\"humidity\":36,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":5},\"wind\":{\"speed\":6.93,\"deg\":164,\"gust\":10.18},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-19 15:00:00\"},{\"dt\":1655661600,\"main\":{\"temp\":90.01,\"feels_like\":86.85,\"temp_min\":90.01,\"temp_max\":90.01,\"pressure\":1012,\"sea_level\":1012,\"grnd_level\":930,\"humidity\":24,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":3},\"wind\":{\"speed\":8.95,\"deg\":211,\"gust\":12.57},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-19 18:00:00\"},{\"dt\":1655672400,\"main\":{\"temp\":95.88,\"feels_like\":92.48,\"temp_min\":95.88,\"temp_max\":95.88,\"pressure\":1009,\"sea_level\":1009,\"grnd_level\":929,\"humidity\":20,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":1},\"wind\":{\"speed\":11.43,\"deg\":224,\"gust\":15.3},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-19 21:00:00\"},{\"dt\":1655683200,\"main\":{\"temp\":96.76,\"feels_like\":93.24,\"temp_min\":96.76,\"temp_max\":96.76,\"pressure\":1007,\"sea_level\":1007,\"grnd_level\":927,\"humidity\":19,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":4},\"wind\":{\"speed\":15.26,\"deg\":242,\"gust\":14.29},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-20 00:00:00\"},{\"dt\":1655694000,\"main\":{\"temp\":88.54,\"feels_like\":85.39,\"temp_min\":88.54,\"temp_max\":88.54,\"pressure\":1009,\"sea_level\":1009,\"grnd_level\":927,\"humidity\":24,\"temp_kf\":0},\"weather\":[{\"id\":801,\"main\":\"Clouds\",\"description\":\"few clouds\",\"icon\":\"02n\"}],\"clouds\":{\"all\":20},\"wind\":{\"speed\":15.73,\"deg\":294,\"gust\":17.2},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-20 03:00:00\"},{\"dt\":1655704800,\"main\":{\"temp\":84.69,\"feels_like\":81.68,\"temp_min\":84.69,\"temp_max\":84.69,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":929,\"humidity\":17,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01n\"}],\"clouds\":{\"all\":10},\"wind\":{\"speed\":5.44,\"deg\":310,\"gust\":7.49},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-20 06:00:00\"},{\"dt\":1655715600,\"main\":{\"temp\":82.22,\"feels_like\":79.93,\"temp_min\":82.22,\"temp_max\":82.22,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":929,\"humidity\":18,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01n\"}],\"clouds\":{\"all\":1},\"wind\":{\"speed\":2.01,\"deg\":327,\"gust\":3},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-20 09:00:00\"},{\"dt\":1655726400,\"main\":{\"temp\":80.55,\"feels_like\":78.93,\"temp_min\":80.55,\"temp_max\":80.55,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":928,\"humidity\":20,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01n\"}],\"clouds\":{\"all\":0},\"wind\":{\"speed\":0.51,\"deg\":58,\"gust\":2.01},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-20 12:00:00\"},{\"dt\":1655737200,\"main\":{\"temp\":85.17,\"feels_like\":82.06,\"temp_min\":85.17,\"temp_max\":85.17,\"pressure\":1012,\"sea_level\":1012,\"grnd_level\":930,\"humidity\":17,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":0},\"wind\":{\"speed\":0.25,\"deg\":270,\"gust\":1.77},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-20 15:00:00\"},{\"dt\":1655748000,\"main\":{\"temp\":91.33,\"feels_like\":87.4,\"temp_min\":91.33,\"temp_max\":91.33,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":930,\"humidity\":18,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":0},\"wind\":{\"speed\":2.37,\"deg\":238,\"gust\":3.09},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-20 18:00:00\"},{\"dt\":1655758800,\"main\":{\"temp\":97.47,\"feels_like\":93.79,\"temp_min\":97.47,\"temp_max\":97.47,\"pressure\":1009,\"sea_level\":1009,\"grnd_level\":929,\"humidity\":18,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":0},\"wind\":{\"speed\":8.03,\"deg\":269,\"gust\":7.4},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-20 21:00:00\"},{\"dt\":1655769600,\"main\":{\"temp\":98.91,\"feels_like\":94.66,\"temp_min\":98.91,\"temp_max\":98.91,\"pressure\":1006,\"sea_level\":1006,\"grnd_level\":926,\"humidity\":15,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":3},\"wind\":{\"speed\":7.31,\"deg\":320,\"gust\":5.3},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-21 00:00:00\"},{\"dt\":1655780400,\"main\":{\"temp\":92.86,\"feels_like\":89.51,\"temp_min\":92.86,\"temp_max\":92.86,\"pressure\":1008,\"sea_level\":1008,\"grnd_level\":927,\"humidity\":22,\"temp_kf\":0},\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04n\"}],\"clouds\":{\"all\":64},\"wind\":{\"speed\":4.97,\"deg\":201,\"gust\":7.25},\"visibility\":10000,\"pop\":0.01,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-21 03:00:00\"},{\"dt\":1655791200,\"main\":{\"temp\":90.5,\"feels_like\":87.06,\"temp_min\":90.5,\"temp_max\":90.5,\"pressure\":1010,\"sea_level\":1010,\"grnd_level\":929,\"humidity\":22,\"temp_kf\":0},\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04n\"}],\"clouds\":{\"all\":79},\"wind\":{\"speed\":2.21,\"deg\":163,\"gust\":6.24},\"visibility\":10000,\"pop\":0.01,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-21 06:00:00\"},{\"dt\":1655802000,\"main\":{\"temp\":86.43,\"feels_like\":83.25,\"temp_min\":86.43,\"temp_max\":86.43,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":929,\"humidity\":21,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03n\"}],\"clouds\":{\"all\":40},\"wind\":{\"speed\":2.13,\"deg\":227,\"gust\":4.36},\"visibility\":10000,\"pop\":0.05,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-21 09:00:00\"},{\"dt\":1655812800,\"main\":{\"temp\":83.01,\"feels_like\":81.14,\"temp_min\":83.01,\"temp_max\":83.01,\"pressure\":1012,\"sea_level\":1012,\"grnd_level\":929,\"humidity\":29,\"temp_kf\":0},\"weather\":[{\"id\":801,\"main\":\"Clouds\",\"description\":\"few clouds\",\"icon\":\"02n\"}],\"clouds\":{\"all\":21},\"wind\":{\"speed\":5.37,\"deg\":111,\"gust\":7.43},\"visibility\":10000,\"pop\":0.02,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-21 12:00:00\"},{\"dt\":1655823600,\"main\":{\"temp\":84.96,\"feels_like\":84.65,\"temp_min\":84.96,\"temp_max\":84.96,\"pressure\":1014,\"sea_level\":1014,\"grnd_level\":931,\"humidity\":42,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03d\"}],\"clouds\":{\"all\":47},\"wind\":{\"speed\":13.4,\"deg\":105,\"gust\":14.23},\"visibility\":10000,\"pop\":0.18,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-21 15:00:00\"},{\"dt\":1655834400,\"main\":{\"temp\":92.01,\"feels_like\":90.64,\"temp_min\":92.01,\"temp_max\":92.01,\"pressure\":1013,\"sea_level\":1013,\"grnd_level\":932,\"humidity\":31,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03d\"}],\"clouds\":{\"all\":28},\"wind\":{\"speed\":9.1,\"deg\":110,\"gust\":9.22},\"visibility\":10000,\"pop\":0.05,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-21 18:00:00\"},{\"dt\":1655845200,\"main\":{\"temp\":97.11,\"feels_like\":95.14,\"temp_min\":97.11,\"temp_max\":97.11,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":930,\"humidity\":24,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03d\"}],\"clouds\":{\"all\":25},\"wind\":{\"speed\":11.68,\"deg\":84,\"gust\":8.61},\"visibility\":10000,\"pop\":0.04,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-21 21:00:00\"},{\"dt\":1655856000,\"main\":{\"temp\":91.31,\"feels_like\":90.3,\"temp_min\":91.31,\"temp_max\":91.31,\"pressure\":1010,\"sea_level\":1010,\"grnd_level\":929,\"humidity\":33,\"temp_kf\":0},\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"}],\"clouds\":{\"all\":31},\"wind\":{\"speed\":13.2,\"deg\":133,\"gust\":15.52},\"visibility\":10000,\"pop\":0.24,\"rain\":{\"3h\":0.23},\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-22 00:00:00\"},{\"dt\":1655866800,\"main\":{\"temp\":90.59,\"feels_like\":88.86,\"temp_min\":90.59,\"temp_max\":90.59,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":929,\"humidity\":31,\"temp_kf\":0},\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04n\"}],\"clouds\":{\"all\":65},\"wind\":{\"speed\":5.7,\"deg\":43,\"gust\":9.55},\"visibility\":10000,\"pop\":0.38,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-22 03:00:00\"},{\"dt\":1655877600,\"main\":{\"temp\":83.21,\"feels_like\":83.61,\"temp_min\":83.21,\"temp_max\":83.21,\"pressure\":1014,\"sea_level\":1014,\"grnd_level\":931,\"humidity\":47,\"temp_kf\":0},\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10n\"}],\"clouds\":{\"all\":55},\"wind\":{\"speed\":15.35,\"deg\":118,\"gust\":18.39},\"visibility\":10000,\"pop\":0.33,\"rain\":{\"3h\":0.25},\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-22 06:00:00\"},{\"dt\":1655888400,\"main\":{\"temp\":81.66,\"feels_like\":82.27,\"temp_min\":81.66,\"temp_max\":81.66,\"pressure\":1012,\"sea_level\":1012,\"grnd_level\":930,\"humidity\":49,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03n\"}],\"clouds\":{\"all\":34},\"wind\":{\"speed\":12.5,\"deg\":101,\"gust\":18.3},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-22 09:00:00\"},{\"dt\":1655899200,\"main\":{\"temp\":80.64,\"feels_like\":81.5,\"temp_min\":80.64,\"temp_max\":80.64,\"pressure\":1013,\"sea_level\":1013,\"grnd_level\":930,\"humidity\":51,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03n\"}],\"clouds\":{\"all\":35},\"wind\":{\"speed\":8.77,\"deg\":85,\"gust\":13.04},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-22 12:00:00\"},{\"dt\":1655910000,\"main\":{\"temp\":85.46,\"feels_like\":84.83,\"temp_min\":85.46,\"temp_max\":85.46,\"pressure\":1013,\"sea_level\":1013,\"grnd_level\":931,\"humidity\":40,\"temp_kf\":0},\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04d\"}],\"clouds\":{\"all\":65},\"wind\":{\"speed\":10.4,\"deg\":93,\"gust\":12.08},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-22 15:00:00\"},{\"dt\":1655920800,\"main\":{\"temp\":94.06,\"feels_like\":92.14,\"temp_min\":94.06,\"temp_max\":94.06,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":931,\"humidity\":27,\"temp_kf\":0},\"weather\":[{\"id\":802,\"main\":\"Clouds\",\"description\":\"scattered clouds\",\"icon\":\"03d\"}],\"clouds\":{\"all\":40},\"wind\":{\"speed\":12.1,\"deg\":111,\"gust\":11.56},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-22 18:00:00\"},{\"dt\":1655931600,\"main\":{\"temp\":99.27,\"feels_like\":96.91,\"temp_min\":99.27,\"temp_max\":99.27,\"pressure\":1009,\"sea_level\":1009,\"grnd_level\":929,\"humidity\":21,\"temp_kf\":0},\"weather\":[{\"id\":800,\"main\":\"Clear\",\"description\":\"clear sky\",\"icon\":\"01d\"}],\"clouds\":{\"all\":2},\"wind\":{\"speed\":7.25,\"deg\":117,\"gust\":10.27},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-22 21:00:00\"},{\"dt\":1655942400,\"main\":{\"temp\":101.89,\"feels_like\":99.1,\"temp_min\":101.89,\"temp_max\":101.89,\"pressure\":1006,\"sea_level\":1006,\"grnd_level\":927,\"humidity\":18,\"temp_kf\":0},\"weather\":[{\"id\":801,\"main\":\"Clouds\",\"description\":\"few clouds\",\"icon\":\"02d\"}],\"clouds\":{\"all\":18},\"wind\":{\"speed\":8.52,\"deg\":200,\"gust\":9.55},\"visibility\":10000,\"pop\":0.09,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-23 00:00:00\"},{\"dt\":1655953200,\"main\":{\"temp\":89.2,\"feels_like\":88.39,\"temp_min\":89.2,\"temp_max\":89.2,\"pressure\":1009,\"sea_level\":1009,\"grnd_level\":928,\"humidity\":36,\"temp_kf\":0},\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10n\"}],\"clouds\":{\"all\":52},\"wind\":{\"speed\":13.11,\"deg\":113,\"gust\":16.87},\"visibility\":10000,\"pop\":0.67,\"rain\":{\"3h\":0.22},\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-23 03:00:00\"},{\"dt\":1655964000,\"main\":{\"temp\":84.56,\"feels_like\":84.6,\"temp_min\":84.56,\"temp_max\":84.56,\"pressure\":1011,\"sea_level\":1011,\"grnd_level\":929,\"humidity\":44,\"temp_kf\":0},\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10n\"}],\"clouds\":{\"all\":76},\"wind\":{\"speed\":10.96,\"deg\":106,\"gust\":14.27},\"visibility\":10000,\"pop\":0.85,\"rain\":{\"3h\":0.39},\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-23 06:00:00\"},{\"dt\":1655974800,\"main\":{\"temp\":83.32,\"feels_like\":83.57,\"temp_min\":83.32,\"temp_max\":83.32,\"pressure\":1010,\"sea_level\":1010,\"grnd_level\":928,\"humidity\":46,\"temp_kf\":0},\"weather\":[{\"id\":804,\"main\":\"Clouds\",\"description\":\"overcast clouds\",\"icon\":\"04n\"}],\"clouds\":{\"all\":91},\"wind\":{\"speed\":7.92,\"deg\":120,\"gust\":9.48},\"visibility\":10000,\"pop\":0.11,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-23 09:00:00\"},{\"dt\":1655985600,\"main\":{\"temp\":83.03,\"feels_like\":82.94,\"temp_min\":83.03,\"temp_max\":83.03,\"pressure\":1010,\"sea_level\":1010,\"grnd_level\":927,\"humidity\":44,\"temp_kf\":0},\"weather\":[{\"id\":804,\"main\":\"Clouds\",\"description\":\"overcast clouds\",\"icon\":\"04n\"}],\"clouds\":{\"all\":95},\"wind\":{\"speed\":7.83,\"deg\":111,\"gust\":9.86},\"visibility\":10000,\"pop\":0.04,\"sys\":{\"pod\":\"n\"},\"dt_txt\":\"2022-06-23 12:00:00\"},{\"dt\":1655996400,\"main\":{\"temp\":88.14,\"feels_like\":86.65,\"temp_min\":88.14,\"temp_max\":88.14,\"pressure\":1010,\"sea_level\":1010,\"grnd_level\":928,\"humidity\":34,\"temp_kf\":0},\"weather\":[{\"id\":804,\"main\":\"Clouds\",\"description\":\"overcast clouds\",\"icon\":\"04d\"}],\"clouds\":{\"all\":98},\"wind\":{\"speed\":6.6,\"deg\":122,\"gust\":8.61},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-23 15:00:00\"},{\"dt\":1656007200,\"main\":{\"temp\":95.56,\"feels_like\":93.45,\"temp_min\":95.56,\"temp_max\":95.56,\"pressure\":1008,\"sea_level\":1008,\"grnd_level\":928,\"humidity\":25,\"temp_kf\":0},\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04d\"}],\"clouds\":{\"all\":51},\"wind\":{\"speed\":3.65,\"deg\":177,\"gust\":4.63},\"visibility\":10000,\"pop\":0,\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-06-23 18:00:00\"}],\"city\":{\"id\":5318313,\"name\":\"Tucson\",\"coord\":{\"lat\":32.2217,\"lon\":-110.9265},\"country\":\"US\",\"population\":520116,\"timezone\":-25200,\"sunrise\":1655554625,\"sunset\":1655605961}}";
+
+
+pub const JSON_RESPONSE_SNOW_MICRO: &str = "{\"cod\":\"200\",\"message\":0,\"cnt\":2,\"list\":[{\"dt\":1671429600,\"main\":{\"temp\":28.4,\"feels_like\":18.7,\"temp_min\":27.9,\"temp_max\":28.4,\"pressure\":1002,\"sea_level\":1002,\"grnd_level\":990,\"humidity\":92,\"temp_kf\":0.28},\"weather\":[{\"id\":601,\"main\":\"Snow\",\"description\":\"snow\",\"icon\":\"13d\"}],\"clouds\":{\"all\":100},\"wind\":{\"speed\":14.2,\"deg\":20,\"gust\":25.1},\"visibility\":420,\"pop\":1,\"snow\":{\"3h\":2.81},\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-12-19 06:00:00\"},{\"dt\":1671440400,\"main\":{\"temp\":32.1,\"feels_like\":24.6,\"temp_min\":32.1,\"temp_max\":32.1,\"pressure\":1003,\"sea_level\":1003,\"grnd_level\":991,\"humidity\":95,\"temp_kf\":0},\"weather\":[{\"id\":616,\"main\":\"Snow\",\"description\":\"rain and snow\",\"icon\":\"13d\"}],\"clouds\":{\"all\":100},\"wind\":{\"speed\":11.8,\"deg\":35,\"gust\":19.4},\"visibility\":1200,\"pop\":0.96,\"rain\":{\"3h\":0.41},\"snow\":{\"3h\":0.93},\"sys\":{\"pod\":\"d\"},\"dt_txt\":\"2022-12-19 09:00:00\"}],\"city\":{\"id\":5746545,\"name\":\"Portland\",\"coord\":{\"lat\":45.5234,\"lon\":-122.6762},\"country\":\"US\",\"population\":583776,\"timezone\":-28800,\"sunrise\":1671464553,\"sunset\":1671496398}}";
+
+
+pub const JSON_CURRENT_RESPONSE: &str = "{\"coord\":{\"lon\":-110.9265,\"lat\":32.2217},\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"},{\"id\":701,\"main\":\"Mist\",\"description\":\"mist\",\"icon\":\"50d\"}],\"base\":\"stations\",\"main\":{\"temp\":91.6,\"feels_like\":89.9,\"temp_min\":88.9,\"temp_max\":94.5,\"pressure\":1010,\"humidity\":31},\"visibility\":10000,\"wind\":{\"speed\":18.41,\"deg\":230,\"gust\":26.46},\"rain\":{\"1h\":0.31},\"clouds\":{\"all\":75},\"dt\":1655583170,\"sys\":{\"type\":2,\"id\":2005140,\"country\":\"US\",\"sunrise\":1655554625,\"sunset\":1655605961},\"timezone\":-25200,\"id\":5318313,\"name\":\"Tucson\",\"cod\":200}";
+
+
+pub const JSON_AIR_POLLUTION_RESPONSE: &str = "{\"coord\":{\"lon\":-110.9265,\"lat\":32.2217},\"list\":[{\"main\":{\"aqi\":2},\"components\":{\"co\":223.64,\"no\":0.02,\"no2\":1.8,\"o3\":96.56,\"so2\":0.98,\"pm2_5\":6.4,\"pm10\":21.3,\"nh3\":0.27},\"dt\":1655586000},{\"main\":{\"aqi\":4},\"components\":{\"co\":240.33,\"no\":0.01,\"no2\":2.1,\"o3\":165.5,\"so2\":1.2,\"pm2_5\":31.7,\"pm10\":88.4,\"nh3\":0.31},\"dt\":1655596800}]}";
+
+
+pub const JSON_ONE_CALL_RESPONSE: &str = "{\"lat\":32.2217,\"lon\":-110.9265,\"timezone\":\"America/Phoenix\",\"timezone_offset\":-25200,\"minutely\":[{\"dt\":1655583180,\"precipitation\":0.0},{\"dt\":1655583240,\"precipitation\":0.1},{\"dt\":1655583300,\"precipitation\":0.2},{\"dt\":1655583360,\"precipitation\":0.3},{\"dt\":1655583420,\"precipitation\":0.4},{\"dt\":1655583480,\"precipitation\":0.0}],\"hourly\":[{\"dt\":1655586000,\"temp\":92.4,\"feels_like\":90.9,\"pressure\":1011,\"humidity\":30,\"dew_point\":55.1,\"uvi\":8.1,\"clouds\":75,\"visibility\":10000,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"}],\"pop\":0.45,\"rain\":{\"1h\":0.22}},{\"dt\":1655589600,\"temp\":91.4,\"feels_like\":89.9,\"pressure\":1011,\"humidity\":31,\"dew_point\":55.1,\"uvi\":8.1,\"clouds\":75,\"visibility\":10000,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"}],\"pop\":0.45,\"rain\":{\"1h\":0.22}},{\"dt\":1655593200,\"temp\":90.4,\"feels_like\":88.9,\"pressure\":1011,\"humidity\":32,\"dew_point\":55.1,\"uvi\":8.1,\"clouds\":75,\"visibility\":10000,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04d\"}],\"pop\":0.45},{\"dt\":1655596800,\"temp\":89.4,\"feels_like\":87.9,\"pressure\":1011,\"humidity\":33,\"dew_point\":55.1,\"uvi\":8.1,\"clouds\":75,\"visibility\":10000,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04d\"}],\"pop\":0.45},{\"dt\":1655600400,\"temp\":88.4,\"feels_like\":86.9,\"pressure\":1011,\"humidity\":34,\"dew_point\":55.1,\"uvi\":8.1,\"clouds\":75,\"visibility\":10000,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04n\"}],\"pop\":0.45},{\"dt\":1655604000,\"temp\":87.4,\"feels_like\":85.9,\"pressure\":1011,\"humidity\":35,\"dew_point\":55.1,\"uvi\":8.1,\"clouds\":75,\"visibility\":10000,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":803,\"main\":\"Clouds\",\"description\":\"broken clouds\",\"icon\":\"04n\"}],\"pop\":0.45}],\"daily\":[{\"dt\":1655575200,\"sunrise\":1655554625,\"sunset\":1655605961,\"moon_phase\":0.7,\"summary\":\"Expect a day of partly cloudy with rain\",\"temp\":{\"day\":92.4,\"min\":77.5,\"max\":96.1,\"night\":81.2,\"eve\":90.3,\"morn\":78.0},\"feels_like\":{\"day\":90.9,\"night\":81.3,\"eve\":87.3,\"morn\":78.1},\"pressure\":1011,\"humidity\":30,\"dew_point\":55.1,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"}],\"clouds\":75,\"pop\":0.45,\"rain\":0.94,\"uvi\":10.2},{\"dt\":1655661600,\"sunrise\":1655641025,\"sunset\":1655692361,\"moon_phase\":0.7,\"summary\":\"Expect a day of partly cloudy with rain\",\"temp\":{\"day\":92.4,\"min\":76.5,\"max\":97.1,\"night\":81.2,\"eve\":90.3,\"morn\":78.0},\"feels_like\":{\"day\":90.9,\"night\":81.3,\"eve\":87.3,\"morn\":78.1},\"pressure\":1011,\"humidity\":30,\"dew_point\":55.1,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"}],\"clouds\":75,\"pop\":0.45,\"rain\":0.94,\"uvi\":10.2},{\"dt\":1655748000,\"sunrise\":1655727425,\"sunset\":1655778761,\"moon_phase\":0.7,\"summary\":\"Expect a day of partly cloudy with rain\",\"temp\":{\"day\":92.4,\"min\":75.5,\"max\":98.1,\"night\":81.2,\"eve\":90.3,\"morn\":78.0},\"feels_like\":{\"day\":90.9,\"night\":81.3,\"eve\":87.3,\"morn\":78.1},\"pressure\":1011,\"humidity\":30,\"dew_point\":55.1,\"wind_speed\":21.43,\"wind_deg\":236,\"wind_gust\":21.74,\"weather\":[{\"id\":500,\"main\":\"Rain\",\"description\":\"light rain\",\"icon\":\"10d\"}],\"clouds\":75,\"pop\":0.45,\"rain\":0.94,\"uvi\":10.2}],\"alerts\":[{\"sender_name\":\"NWS Tucson (Southeast Arizona)\",\"event\":\"Excessive Heat Warning\",\"start\":1655571600,\"end\":1655604000,\"description\":\"...EXCESSIVE HEAT WARNING REMAINS IN EFFECT UNTIL 7 PM MST THIS EVENING...\\n* WHAT...Dangerously hot conditions with temperatures up to 110.\",\"tags\":[\"Extreme temperature value\"]}]}";
+
+
+pub const JSON_OPEN_METEO_RESPONSE: &str = "{\"latitude\":32.2217,\"longitude\":-110.9265,\"utc_offset_seconds\":-25200,\"timezone\":\"America/Phoenix\",\"current\":{\"time\":1655583300,\"interval\":900,\"temperature_2m\":91.6,\"apparent_temperature\":89.9,\"relative_humidity_2m\":31,\"precipitation\":0.2,\"rain\":0.2,\"snowfall\":0.0,\"weathercode\":61,\"cloud_cover\":75,\"pressure_msl\":1011.3,\"wind_speed_10m\":21.4,\"wind_direction_10m\":236,\"wind_gusts_10m\":24.8},\"hourly\":{\"time\":[1655596800,1655600400,1655604000,1655607600,1655611200,1655614800,1655618400,1655622000,1655625600,1655629200,1655632800,1655636400,1655640000,1655643600,1655647200,1655650800,1655654400,1655658000,1655661600,1655665200,1655668800,1655672400,1655676000,1655679600,1655683200,1655686800,1655690400,1655694000,1655697600,1655701200,1655704800,1655708400,1655712000,1655715600,1655719200,1655722800,1655726400,1655730000,1655733600,1655737200,1655740800,1655744400,1655748000,1655751600,1655755200,1655758800,1655762400,1655766000],\"temperature_2m\":[92.7,91.1,89.0,86.6,84.0,81.4,79.0,76.9,75.3,74.3,74.0,74.3,75.3,76.9,79.0,81.4,84.0,86.6,89.0,91.1,92.7,93.7,94.0,93.7,92.7,91.1,89.0,86.6,84.0,81.4,79.0,76.9,75.3,74.3,74.0,74.3,75.3,76.9,79.0,81.4,84.0,86.6,89.0,91.1,92.7,93.7,94.0,93.7],\"apparent_temperature\":[91.3,89.7,87.6,85.2,82.6,80.0,77.6,75.5,73.9,72.9,72.6,72.9,73.9,75.5,77.6,80.0,82.6,85.2,87.6,89.7,91.3,92.3,92.6,92.3,91.3,89.7,87.6,85.2,82.6,80.0,77.6,75.5,73.9,72.9,72.6,72.9,73.9,75.5,77.6,80.0,82.6,85.2,87.6,89.7,91.3,92.3,92.6,92.3],\"relative_humidity_2m\":[30,31,32,33,34,35,36,37,38,39,40,41,30,31,32,33,34,35,36,37,38,39,40,41,30,31,32,33,34,35,36,37,38,39,40,41,30,31,32,33,34,35,36,37,38,39,40,41],\"precipitation\":[0.0,0.0,0.4,0.4,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0],\"rain\":[0.0,0.0,0.4,0.4,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0],\"snowfall\":[0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0],\"wind_speed_10m\":[8.0,9.3,10.6,11.9,13.2,14.5,15.8,8.0,9.3,10.6,11.9,13.2,14.5,15.8,8.0,9.3,10.6,11.9,13.2,14.5,15.8,8.0,9.3,10.6,11.9,13.2,14.5,15.8,8.0,9.3,10.6,11.9,13.2,14.5,15.8,8.0,9.3,10.6,11.9,13.2,14.5,15.8,8.0,9.3,10.6,11.9,13.2,14.5],\"wind_direction_10m\":[200,205,210,215,220,225,230,235,240,245,250,255,260,265,270,275,280,285,290,295,300,305,310,315,320,325,330,335,340,345,350,355,0,5,10,15,20,25,30,35,40,45,50,55,60,65,70,75],\"is_day\":[1,1,1,0,0,0,0,0,0,0,0,0,0,1,1,1,1,1,1,1,1,1,1,1,1,1,1,0,0,0,0,0,0,0,0,0,0,1,1,1,1,1,1,1,1,1,1,1],\"weathercode\":[2,0,61,61,0,2,0,0,0,0,2,0,0,0,0,2,0,0,0,0,2,0,0,0,0,2,0,0,0,0,2,0,0,0,0,2,0,0,0,0,2,0,0,0,0,2,0,0]},\"daily\":{\"time\":[1655535600,1655622000,1655708400],\"weathercode\":[61,2,0],\"temperature_2m_max\":[96.1,94.2,95.0],\"temperature_2m_min\":[77.5,76.8,78.1],\"apparent_temperature_max\":[94.0,92.6,93.1],\"precipitation_sum\":[0.8,0.0,0.0],\"rain_sum\":[0.8,0.0,0.0],\"snowfall_sum\":[0.0,0.0,0.0],\"wind_speed_10m_max\":[21.4,15.2,12.9],\"wind_direction_10m_dominant\":[236,210,190],\"sunrise\":[1655554625,1655641040,1655727457],\"sunset\":[1655605961,1655692372,1655778781]}}";
+
+
+pub const JSON_NWS_POINTS_RESPONSE: &str = "{\"properties\":{\"gridId\":\"TWC\",\"gridX\":91,\"gridY\":49,\"forecast\":\"https://api.weather.gov/gridpoints/TWC/91,49/forecast\",\"forecastHourly\":\"https://api.weather.gov/gridpoints/TWC/91,49/forecast/hourly\",\"timeZone\":\"America/Phoenix\",\"relativeLocation\":{\"properties\":{\"city\":\"Tucson\",\"state\":\"AZ\"}}}}";
+
+
+pub const JSON_NWS_HOURLY_RESPONSE: &str = "{\"type\":\"Feature\",\"properties\":{\"updated\":\"2022-06-18T23:11:02+00:00\",\"units\":\"us\",\"periods\":[{\"number\":1,\"name\":\"\",\"startTime\":\"2022-06-18T17:00:00-07:00\",\"endTime\":\"2022-06-18T18:00:00-07:00\",\"isDaytime\":true,\"temperature\":93,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"5 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":2,\"name\":\"\",\"startTime\":\"2022-06-18T18:00:00-07:00\",\"endTime\":\"2022-06-18T19:00:00-07:00\",\"isDaytime\":true,\"temperature\":91,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":31},\"windSpeed\":\"6 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":3,\"name\":\"\",\"startTime\":\"2022-06-18T19:00:00-07:00\",\"endTime\":\"2022-06-18T20:00:00-07:00\",\"isDaytime\":false,\"temperature\":89,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":40},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":32},\"windSpeed\":\"10 to 15 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/night/tsra_sct,40?size=small\",\"shortForecast\":\"Chance Showers And Thunderstorms\",\"detailedForecast\":\"\"},{\"number\":4,\"name\":\"\",\"startTime\":\"2022-06-18T20:00:00-07:00\",\"endTime\":\"2022-06-18T21:00:00-07:00\",\"isDaytime\":false,\"temperature\":87,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":40},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":33},\"windSpeed\":\"10 to 15 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/night/tsra_sct,40?size=small\",\"shortForecast\":\"Chance Showers And Thunderstorms\",\"detailedForecast\":\"\"},{\"number\":5,\"name\":\"\",\"startTime\":\"2022-06-18T21:00:00-07:00\",\"endTime\":\"2022-06-18T22:00:00-07:00\",\"isDaytime\":false,\"temperature\":84,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":34},\"windSpeed\":\"9 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":6,\"name\":\"\",\"startTime\":\"2022-06-18T22:00:00-07:00\",\"endTime\":\"2022-06-18T23:00:00-07:00\",\"isDaytime\":false,\"temperature\":81,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":35},\"windSpeed\":\"10 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":7,\"name\":\"\",\"startTime\":\"2022-06-18T23:00:00-07:00\",\"endTime\":\"2022-06-19T00:00:00-07:00\",\"isDaytime\":false,\"temperature\":79,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":36},\"windSpeed\":\"5 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":8,\"name\":\"\",\"startTime\":\"2022-06-19T00:00:00-07:00\",\"endTime\":\"2022-06-19T01:00:00-07:00\",\"isDaytime\":false,\"temperature\":77,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":37},\"windSpeed\":\"6 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":9,\"name\":\"\",\"startTime\":\"2022-06-19T01:00:00-07:00\",\"endTime\":\"2022-06-19T02:00:00-07:00\",\"isDaytime\":false,\"temperature\":75,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":38},\"windSpeed\":\"7 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":10,\"name\":\"\",\"startTime\":\"2022-06-19T02:00:00-07:00\",\"endTime\":\"2022-06-19T03:00:00-07:00\",\"isDaytime\":false,\"temperature\":74,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":39},\"windSpeed\":\"8 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":11,\"name\":\"\",\"startTime\":\"2022-06-19T03:00:00-07:00\",\"endTime\":\"2022-06-19T04:00:00-07:00\",\"isDaytime\":false,\"temperature\":74,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"9 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":12,\"name\":\"\",\"startTime\":\"2022-06-19T04:00:00-07:00\",\"endTime\":\"2022-06-19T05:00:00-07:00\",\"isDaytime\":false,\"temperature\":74,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":31},\"windSpeed\":\"10 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":13,\"name\":\"\",\"startTime\":\"2022-06-19T05:00:00-07:00\",\"endTime\":\"2022-06-19T06:00:00-07:00\",\"isDaytime\":true,\"temperature\":75,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":32},\"windSpeed\":\"5 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":14,\"name\":\"\",\"startTime\":\"2022-06-19T06:00:00-07:00\",\"endTime\":\"2022-06-19T07:00:00-07:00\",\"isDaytime\":true,\"temperature\":77,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":33},\"windSpeed\":\"6 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":15,\"name\":\"\",\"startTime\":\"2022-06-19T07:00:00-07:00\",\"endTime\":\"2022-06-19T08:00:00-07:00\",\"isDaytime\":true,\"temperature\":79,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":34},\"windSpeed\":\"7 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":16,\"name\":\"\",\"startTime\":\"2022-06-19T08:00:00-07:00\",\"endTime\":\"2022-06-19T09:00:00-07:00\",\"isDaytime\":true,\"temperature\":81,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":35},\"windSpeed\":\"8 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":17,\"name\":\"\",\"startTime\":\"2022-06-19T09:00:00-07:00\",\"endTime\":\"2022-06-19T10:00:00-07:00\",\"isDaytime\":true,\"temperature\":84,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":36},\"windSpeed\":\"9 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":18,\"name\":\"\",\"startTime\":\"2022-06-19T10:00:00-07:00\",\"endTime\":\"2022-06-19T11:00:00-07:00\",\"isDaytime\":true,\"temperature\":87,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":37},\"windSpeed\":\"10 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":19,\"name\":\"\",\"startTime\":\"2022-06-19T11:00:00-07:00\",\"endTime\":\"2022-06-19T12:00:00-07:00\",\"isDaytime\":true,\"temperature\":89,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":38},\"windSpeed\":\"5 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":20,\"name\":\"\",\"startTime\":\"2022-06-19T12:00:00-07:00\",\"endTime\":\"2022-06-19T13:00:00-07:00\",\"isDaytime\":true,\"temperature\":91,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":39},\"windSpeed\":\"6 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":21,\"name\":\"\",\"startTime\":\"2022-06-19T13:00:00-07:00\",\"endTime\":\"2022-06-19T14:00:00-07:00\",\"isDaytime\":true,\"temperature\":93,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"7 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":22,\"name\":\"\",\"startTime\":\"2022-06-19T14:00:00-07:00\",\"endTime\":\"2022-06-19T15:00:00-07:00\",\"isDaytime\":true,\"temperature\":94,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":31},\"windSpeed\":\"8 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":23,\"name\":\"\",\"startTime\":\"2022-06-19T15:00:00-07:00\",\"endTime\":\"2022-06-19T16:00:00-07:00\",\"isDaytime\":true,\"temperature\":94,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":32},\"windSpeed\":\"9 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":24,\"name\":\"\",\"startTime\":\"2022-06-19T16:00:00-07:00\",\"endTime\":\"2022-06-19T17:00:00-07:00\",\"isDaytime\":true,\"temperature\":94,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":33},\"windSpeed\":\"10 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":25,\"name\":\"\",\"startTime\":\"2022-06-19T17:00:00-07:00\",\"endTime\":\"2022-06-19T18:00:00-07:00\",\"isDaytime\":true,\"temperature\":93,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":34},\"windSpeed\":\"5 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":26,\"name\":\"\",\"startTime\":\"2022-06-19T18:00:00-07:00\",\"endTime\":\"2022-06-19T19:00:00-07:00\",\"isDaytime\":true,\"temperature\":91,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":35},\"windSpeed\":\"6 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=small\",\"shortForecast\":\"Mostly Sunny\",\"detailedForecast\":\"\"},{\"number\":27,\"name\":\"\",\"startTime\":\"2022-06-19T19:00:00-07:00\",\"endTime\":\"2022-06-19T20:00:00-07:00\",\"isDaytime\":false,\"temperature\":89,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":36},\"windSpeed\":\"7 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":28,\"name\":\"\",\"startTime\":\"2022-06-19T20:00:00-07:00\",\"endTime\":\"2022-06-19T21:00:00-07:00\",\"isDaytime\":false,\"temperature\":87,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":37},\"windSpeed\":\"8 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":29,\"name\":\"\",\"startTime\":\"2022-06-19T21:00:00-07:00\",\"endTime\":\"2022-06-19T22:00:00-07:00\",\"isDaytime\":false,\"temperature\":84,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":38},\"windSpeed\":\"9 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":30,\"name\":\"\",\"startTime\":\"2022-06-19T22:00:00-07:00\",\"endTime\":\"2022-06-19T23:00:00-07:00\",\"isDaytime\":false,\"temperature\":81,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":39},\"windSpeed\":\"10 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":31,\"name\":\"\",\"startTime\":\"2022-06-19T23:00:00-07:00\",\"endTime\":\"2022-06-20T00:00:00-07:00\",\"isDaytime\":false,\"temperature\":79,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"5 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":32,\"name\":\"\",\"startTime\":\"2022-06-20T00:00:00-07:00\",\"endTime\":\"2022-06-20T01:00:00-07:00\",\"isDaytime\":false,\"temperature\":77,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":31},\"windSpeed\":\"6 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":33,\"name\":\"\",\"startTime\":\"2022-06-20T01:00:00-07:00\",\"endTime\":\"2022-06-20T02:00:00-07:00\",\"isDaytime\":false,\"temperature\":75,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":32},\"windSpeed\":\"7 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":34,\"name\":\"\",\"startTime\":\"2022-06-20T02:00:00-07:00\",\"endTime\":\"2022-06-20T03:00:00-07:00\",\"isDaytime\":false,\"temperature\":74,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":33},\"windSpeed\":\"8 mph\",\"windDirection\":\"WSW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":35,\"name\":\"\",\"startTime\":\"2022-06-20T03:00:00-07:00\",\"endTime\":\"2022-06-20T04:00:00-07:00\",\"isDaytime\":false,\"temperature\":74,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":34},\"windSpeed\":\"9 mph\",\"windDirection\":\"W\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"},{\"number\":36,\"name\":\"\",\"startTime\":\"2022-06-20T04:00:00-07:00\",\"endTime\":\"2022-06-20T05:00:00-07:00\",\"isDaytime\":false,\"temperature\":74,\"temperatureUnit\":\"F\",\"temperatureTrend\":null,\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":5},\"dewpoint\":{\"unitCode\":\"wmoUnit:degC\",\"value\":10.0},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":35},\"windSpeed\":\"10 mph\",\"windDirection\":\"S\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=small\",\"shortForecast\":\"Clear\",\"detailedForecast\":\"\"}]}}";
+
+
+pub const JSON_NWS_FORECAST_RESPONSE: &str = "{\"type\":\"Feature\",\"properties\":{\"units\":\"us\",\"periods\":[{\"number\":1,\"name\":\"This Afternoon\",\"startTime\":\"2022-06-18T15:00:00-07:00\",\"endTime\":\"2022-06-19T03:00:00-07:00\",\"isDaytime\":true,\"temperature\":96,\"temperatureUnit\":\"F\",\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":40},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"10 to 20 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/tsra_sct,40?size=medium\",\"shortForecast\":\"Chance Showers And Thunderstorms\",\"detailedForecast\":\"\"},{\"number\":2,\"name\":\"Tonight\",\"startTime\":\"2022-06-18T18:00:00-07:00\",\"endTime\":\"2022-06-19T06:00:00-07:00\",\"isDaytime\":false,\"temperature\":77,\"temperatureUnit\":\"F\",\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":40},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"10 to 20 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/night/tsra_sct,30?size=medium\",\"shortForecast\":\"Chance Showers And Thunderstorms\",\"detailedForecast\":\"\"},{\"number\":3,\"name\":\"Sunday\",\"startTime\":\"2022-06-19T06:00:00-07:00\",\"endTime\":\"2022-06-19T18:00:00-07:00\",\"isDaytime\":true,\"temperature\":94,\"temperatureUnit\":\"F\",\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":null},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"10 to 20 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/few?size=medium\",\"shortForecast\":\"Sunny\",\"detailedForecast\":\"\"},{\"number\":4,\"name\":\"Sunday Night\",\"startTime\":\"2022-06-19T18:00:00-07:00\",\"endTime\":\"2022-06-20T06:00:00-07:00\",\"isDaytime\":false,\"temperature\":76,\"temperatureUnit\":\"F\",\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":null},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"10 to 20 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/night/skc?size=medium\",\"shortForecast\":\"Sunny\",\"detailedForecast\":\"\"},{\"number\":5,\"name\":\"Monday\",\"startTime\":\"2022-06-20T06:00:00-07:00\",\"endTime\":\"2022-06-20T18:00:00-07:00\",\"isDaytime\":true,\"temperature\":95,\"temperatureUnit\":\"F\",\"probabilityOfPrecipitation\":{\"unitCode\":\"wmoUnit:percent\",\"value\":null},\"relativeHumidity\":{\"unitCode\":\"wmoUnit:percent\",\"value\":30},\"windSpeed\":\"10 to 20 mph\",\"windDirection\":\"SW\",\"icon\":\"https://api.weather.gov/icons/land/day/sct?size=medium\",\"shortForecast\":\"Sunny\",\"detailedForecast\":\"\"}]}}";
+
+
+pub const JSON_NWS_ALERTS_RESPONSE: &str = "{\"type\":\"FeatureCollection\",\"features\":[{\"properties\":{\"senderName\":\"NWS Tucson AZ\",\"event\":\"Excessive Heat Warning\",\"headline\":\"Excessive Heat Warning issued June 18\",\"effective\":\"2022-06-18T10:00:00-07:00\",\"onset\":\"2022-06-18T10:00:00-07:00\",\"expires\":\"2022-06-18T20:00:00-07:00\",\"ends\":\"2022-06-19T20:00:00-07:00\",\"severity\":\"Severe\",\"urgency\":\"Expected\",\"description\":\"* WHAT...Dangerously hot conditions with temperatures up to 112.\\n\\n* WHERE...Tucson Metro Area.\"}}]}";
+
+
+pub const JSON_MET_NO_RESPONSE: &str = "{\"type\":\"Feature\",\"geometry\":{\"type\":\"Point\",\"coordinates\":[10.7461,59.9127,16]},\"properties\":{\"meta\":{\"updated_at\":\"2022-06-18T17:31:02Z\",\"units\":{\"air_pressure_at_sea_level\":\"hPa\",\"air_temperature\":\"celsius\",\"cloud_area_fraction\":\"%\",\"precipitation_amount\":\"mm\",\"relative_humidity\":\"%\",\"wind_from_direction\":\"degrees\",\"wind_speed\":\"m/s\"}},\"timeseries\":[{\"time\":\"2022-06-18T18:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":17.6,\"cloud_area_fraction\":40.0,\"relative_humidity\":76.0,\"wind_from_direction\":200.0,\"wind_speed\":3.0}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"}}}},{\"time\":\"2022-06-18T19:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":16.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":76.8,\"wind_from_direction\":201.0,\"wind_speed\":3.7}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-18T20:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":14.4,\"cloud_area_fraction\":40.0,\"relative_humidity\":77.6,\"wind_from_direction\":202.0,\"wind_speed\":4.4}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-18T21:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":13.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":78.4,\"wind_from_direction\":203.0,\"wind_speed\":5.1}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-18T22:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":11.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":60.0,\"wind_from_direction\":204.0,\"wind_speed\":5.8}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_night\"}}}},{\"time\":\"2022-06-18T23:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":60.8,\"wind_from_direction\":205.0,\"wind_speed\":3.0}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-19T00:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":61.6,\"wind_from_direction\":206.0,\"wind_speed\":3.7}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-19T01:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":62.4,\"wind_from_direction\":207.0,\"wind_speed\":4.4}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-19T02:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":63.2,\"wind_from_direction\":208.0,\"wind_speed\":5.1}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T03:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":64.0,\"wind_from_direction\":209.0,\"wind_speed\":5.8}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"}}}},{\"time\":\"2022-06-19T04:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":11.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":64.8,\"wind_from_direction\":210.0,\"wind_speed\":3.0}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T05:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":13.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":65.6,\"wind_from_direction\":211.0,\"wind_speed\":3.7}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T06:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":14.4,\"cloud_area_fraction\":40.0,\"relative_humidity\":66.4,\"wind_from_direction\":212.0,\"wind_speed\":4.4}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T07:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":16.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":67.2,\"wind_from_direction\":213.0,\"wind_speed\":5.1}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T08:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":17.6,\"cloud_area_fraction\":40.0,\"relative_humidity\":68.0,\"wind_from_direction\":214.0,\"wind_speed\":5.8}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"}}}},{\"time\":\"2022-06-19T09:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":19.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":68.8,\"wind_from_direction\":215.0,\"wind_speed\":3.0}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T10:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":20.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":69.6,\"wind_from_direction\":216.0,\"wind_speed\":3.7}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T11:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":21.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":70.4,\"wind_from_direction\":217.0,\"wind_speed\":4.4}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T12:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":21.8,\"cloud_area_fraction\":90.0,\"relative_humidity\":71.2,\"wind_from_direction\":218.0,\"wind_speed\":5.1}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"},\"details\":{\"precipitation_amount\":0.6}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"},\"details\":{\"precipitation_amount\":1.2}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"}}}},{\"time\":\"2022-06-19T13:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":22.0,\"cloud_area_fraction\":90.0,\"relative_humidity\":72.0,\"wind_from_direction\":219.0,\"wind_speed\":5.8}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"},\"details\":{\"precipitation_amount\":0.6}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"},\"details\":{\"precipitation_amount\":1.2}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"}}}},{\"time\":\"2022-06-19T14:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":21.8,\"cloud_area_fraction\":90.0,\"relative_humidity\":72.8,\"wind_from_direction\":220.0,\"wind_speed\":3.0}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"},\"details\":{\"precipitation_amount\":0.6}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"},\"details\":{\"precipitation_amount\":1.2}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"lightrain\"}}}},{\"time\":\"2022-06-19T15:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":21.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":73.6,\"wind_from_direction\":221.0,\"wind_speed\":3.7}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T16:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":20.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":74.4,\"wind_from_direction\":222.0,\"wind_speed\":4.4}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T17:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":19.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":75.2,\"wind_from_direction\":223.0,\"wind_speed\":5.1}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T18:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":17.6,\"cloud_area_fraction\":40.0,\"relative_humidity\":76.0,\"wind_from_direction\":224.0,\"wind_speed\":5.8}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"}}}},{\"time\":\"2022-06-19T19:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":16.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":76.8,\"wind_from_direction\":225.0,\"wind_speed\":3.0}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T20:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":14.4,\"cloud_area_fraction\":40.0,\"relative_humidity\":77.6,\"wind_from_direction\":226.0,\"wind_speed\":3.7}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-19T21:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":13.0,\"cloud_area_fraction\":40.0,\"relative_humidity\":78.4,\"wind_from_direction\":227.0,\"wind_speed\":4.4}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-19T22:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":11.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":60.0,\"wind_from_direction\":228.0,\"wind_speed\":5.1}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_night\"}}}},{\"time\":\"2022-06-19T23:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":60.8,\"wind_from_direction\":229.0,\"wind_speed\":5.8}},\"next_1_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-20T00:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":61.6,\"wind_from_direction\":230.0,\"wind_speed\":3.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-20T06:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":14.4,\"cloud_area_fraction\":40.0,\"relative_humidity\":66.4,\"wind_from_direction\":231.0,\"wind_speed\":3.7}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-20T12:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":21.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":71.2,\"wind_from_direction\":232.0,\"wind_speed\":4.4}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-20T18:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":17.6,\"cloud_area_fraction\":40.0,\"relative_humidity\":76.0,\"wind_from_direction\":233.0,\"wind_speed\":5.1}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"}}}},{\"time\":\"2022-06-21T00:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":61.6,\"wind_from_direction\":234.0,\"wind_speed\":5.8}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-21T06:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":14.4,\"cloud_area_fraction\":40.0,\"relative_humidity\":66.4,\"wind_from_direction\":235.0,\"wind_speed\":3.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-21T12:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":21.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":71.2,\"wind_from_direction\":236.0,\"wind_speed\":3.7}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-21T18:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":17.6,\"cloud_area_fraction\":40.0,\"relative_humidity\":76.0,\"wind_from_direction\":237.0,\"wind_speed\":4.4}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"partlycloudy_day\"}}}},{\"time\":\"2022-06-22T00:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":10.2,\"cloud_area_fraction\":40.0,\"relative_humidity\":61.6,\"wind_from_direction\":238.0,\"wind_speed\":5.1}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_night\"}}}},{\"time\":\"2022-06-22T06:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":14.4,\"cloud_area_fraction\":40.0,\"relative_humidity\":66.4,\"wind_from_direction\":239.0,\"wind_speed\":5.8}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}},{\"time\":\"2022-06-22T12:00:00Z\",\"data\":{\"instant\":{\"details\":{\"air_pressure_at_sea_level\":1012.4,\"air_temperature\":21.8,\"cloud_area_fraction\":40.0,\"relative_humidity\":71.2,\"wind_from_direction\":240.0,\"wind_speed\":3.0}},\"next_6_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"},\"details\":{\"precipitation_amount\":0.0}},\"next_12_hours\":{\"summary\":{\"symbol_code\":\"fair_day\"}}}}]}}";
@@ -0,0 +1,553 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+
+use crate::backend::{estimate_daylight, group_days, solar_daylight, three_hourly, City, Clock, Condition, DayKey, Granularity, Precipitation};
+use crate::display::{Alert, CurrentConditions, WeatherForecast, WeatherSection};
+use crate::http::HttpClient;
+use crate::openmeteo::{locate, Place, GEOCODING_URL};
+use crate::provider::{Query, WeatherProvider};
+use crate::request::{ApiError, Location, Units, USER_AGENT};
+
+// US National Weather Service provider (api.weather.gov), free and keyless
+// but US only. A points lookup maps coordinates onto the forecast office
+// grid, whose 12 hour and hourly forecasts are then fetched as GeoJSON.
+// The service rejects requests without an identifying User-Agent.
+
+/*
+   Snippet of points and forecastHourly JSON data
+
+   properties: {
+     forecast: "https://api.weather.gov/gridpoints/TWC/91,49/forecast",
+     forecastHourly: "https://api.weather.gov/gridpoints/TWC/91,49/forecast/hourly",
+     timeZone: "America/Phoenix",
+     relativeLocation: { properties: { city: "Tucson", state: "AZ" } }
+   }
+
+   properties: {
+     periods: [{
+       number: 1, name: "", startTime: "2022-06-18T17:00:00-07:00", endTime: "2022-06-18T18:00:00-07:00",
+       isDaytime: true, temperature: 93, temperatureUnit: "F",
+       probabilityOfPrecipitation: { unitCode: "wmoUnit:percent", value: 40 },
+       relativeHumidity: { unitCode: "wmoUnit:percent", value: 30 },
+       windSpeed: "10 to 15 mph", windDirection: "SW",
+       icon: "https://api.weather.gov/icons/land/day/tsra_sct,40?size=small",
+       shortForecast: "Chance Showers And Thunderstorms"
+     }, ...]
+   }
+*/
+
+const BASE_API_URL: &str = "https://api.weather.gov";
+const GEOJSON: &str = "application/geo+json";
+
+#[derive(Debug)]
+pub struct Nws {
+    base_url: String,
+    geocoding_url: String,
+    http: HttpClient,
+}
+
+impl Default for Nws {
+    fn default() -> Self { Nws::new() }
+}
+
+impl Nws {
+    pub fn new() -> Nws {
+        Nws {
+            base_url: BASE_API_URL.to_owned(),
+            geocoding_url: GEOCODING_URL.to_owned(),
+            http: HttpClient::new(),
+        }
+    }
+
+    // Point at different hosts e.g. a local stand-in server
+    pub fn with_base_url(mut self, base_url: &str, geocoding_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self.geocoding_url = geocoding_url.to_owned();
+        self
+    }
+
+    pub fn with_http(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    // NWS has no geocoder, place names are resolved with Open-Meteo's
+    fn place(&self, query: &Query) -> Result<Place, ApiError> {
+        match query.location {
+            Location::Zip(..) | Location::Id(_) => Err(ApiError::Unsupported("nws", "zip or city id locations")),
+            _ => locate(&self.http, &self.geocoding_url, query),
+        }
+    }
+
+    // Grid forecast urls covering the place, there are none outside the US
+    fn points(&self, place: &Place) -> Result<PointProperties, ApiError> {
+        let url = format!("{}/points/{:.4},{:.4}", self.base_url, place.latitude, place.longitude);
+        let body = match self.get(&url) {
+            Err(ApiError::Request(e)) if matches!(*e, ureq::Error::Status(404, _)) => {
+                return Err(ApiError::NotCovered(format!("{:.4},{:.4}", place.latitude, place.longitude)))
+            },
+            body => body?,
+        };
+        let points: Points = serde_json::from_str(&body)?;
+
+        Ok(points.properties)
+    }
+
+    // 12 hour day and night periods, or hourly periods
+    fn periods(&self, url: &str, units: Units) -> Result<Vec<Period>, ApiError> {
+        let units = match units {
+            Units::Imperial => "us",
+            Units::Metric => "si",
+        };
+
+        let forecast = GridForecast::parse(&self.get(&format!("{}?units={}", url, units))?)?;
+
+        Ok(forecast.properties.periods)
+    }
+
+    fn alerts(&self, place: &Place, clock: Clock) -> Result<Vec<Alert>, ApiError> {
+        let url = format!("{}/alerts/active?point={:.4},{:.4}", self.base_url, place.latitude, place.longitude);
+
+        Ok(AlertCollection::parse(&self.get(&url)?)?.transform(clock))
+    }
+
+    // Places HTTP get (blocking) call with the headers NWS requires
+    fn get(&self, url: &str) -> Result<String, ApiError> {
+        let request = self.http.get(url)
+            .set("User-Agent", USER_AGENT)
+            .set("Accept", GEOJSON);
+
+        Ok(self.http.call(request)?.into_string()?)
+    }
+}
+
+impl WeatherProvider for Nws {
+    fn name(&self) -> &'static str {
+        "nws"
+    }
+
+    fn current(&self, query: &Query) -> Result<CurrentConditions, ApiError> {
+        let place = self.place(query)?;
+        let points = self.points(&place)?;
+        let hourly = self.periods(&points.forecast_hourly, query.units)?;
+
+        current(&points, &hourly, query.units, query.clock)
+    }
+
+    fn forecast(&self, query: &Query) -> Result<WeatherForecast, ApiError> {
+        let place = self.place(query)?;
+        let points = self.points(&place)?;
+        let hourly = self.periods(&points.forecast_hourly, query.units)?;
+        let current = current(&points, &hourly, query.units, query.clock)?;
+        let clock = query.clock;
+        let city = city(&points, &place, &hourly);
+
+        let (list, filter) = match query.granularity {
+            Granularity::Minutely => return Err(ApiError::Unsupported("nws", "minutely forecasts")),
+            Granularity::Hourly => (hourly.iter().map(|p| p.transform(&clock, query.units)).collect::<Result<Vec<_>, _>>()?, Some(&query.filter)),
+            // hourly periods carry no amounts, rolling them up still gives
+            // the 3 hour temperature range and chance of precipitation
+            Granularity::ThreeHour => {
+                let list = hourly.iter()
+                    .map(|p| {
+                        let (day, hour, ws) = p.transform(&clock, query.units)?;
+                        let span = (ws.timestamp, ws.timestamp + 3600);
+                        Ok((day, hour, ws, span))
+                    })
+                    .collect::<Result<Vec<_>, ApiError>>()?;
+                (three_hourly(list), Some(&query.filter))
+            },
+            Granularity::Daily => (daily(&self.periods(&points.forecast, query.units)?, &clock, query.units)?, None),
+        };
+
+        let map = group_days(list, filter, city.daylight_hours(&clock));
+        let forecast = WeatherForecast::new(city, clock, map)
+            .with_granularity(query.granularity)
+            .with_current(current);
+
+        if query.alerts {
+            return Ok(forecast.with_alerts(self.alerts(&place, clock)?))
+        }
+
+        Ok(forecast)
+    }
+}
+
+// Header details, sunrise and sunset are worked out for the place on the
+// first period's date. NWS fixes isDaytime at 6am to 6pm, so where the
+// periods switch is only a fallback for polar day and night
+fn city(points: &PointProperties, place: &Place, hourly: &[Period]) -> City {
+    let starts: Vec<DateTime<FixedOffset>> = hourly.iter().filter_map(|p| p.start().ok()).collect();
+    let offset = starts.first().map(|s| s.offset().local_minus_utc()).unwrap_or_default();
+    let (sunrise, sunset) = starts.first()
+        .and_then(|s| solar_daylight(place.latitude, place.longitude, s.naive_local().date()))
+        .unwrap_or_else(|| estimate_daylight(starts.iter().zip(hourly).map(|(s, p)| (s.timestamp() as u64, p.is_daytime))));
+
+    City::new(
+        points.relative_location.name(),
+        "US".to_owned(),
+        place.latitude,
+        place.longitude,
+        sunrise,
+        sunset,
+        offset,
+    )
+}
+
+// NWS has no "now" outside of station observations, the
+// hourly period in progress stands in for current conditions
+fn current(points: &PointProperties, hourly: &[Period], units: Units, clock: Clock) -> Result<CurrentConditions, ApiError> {
+    let period = hourly.first().ok_or(ApiError::NoConditions("no hourly periods".to_owned()))?;
+    let start = period.start()?;
+    let condition = period.condition();
+    let today = &hourly[..hourly.len().min(24)];
+    let min = today.iter().map(|p| p.temperature).fold(f32::MAX, f32::min);
+    let max = today.iter().map(|p| p.temperature).fold(f32::MIN, f32::max);
+
+    Ok(CurrentConditions {
+        place: points.relative_location.name(),
+        datetime: clock.localize(start.timestamp() as u64, start.offset().local_minus_utc()).format("%a %m-%d %H:%M").to_string(),
+        temp: period.temperature,
+        feels_like: period.temperature,
+        temp_min: min,
+        temp_max: max,
+        pressure: 0,
+        humidity: period.humidity(),
+        conditions: vec![condition],
+        clouds: 0,
+        visibility: None,
+        wind_speed: period.wind_speed(units),
+        wind_deg: period.wind_deg(),
+        wind_gust: None,
+        rain_one_hour: None,
+        snow_one_hour: None,
+        air_quality: None,
+    })
+}
+
+// Merges the day and night periods falling on the same date,
+// the day supplies the high and conditions, the night the low
+fn daily(periods: &[Period], clock: &Clock, units: Units) -> Result<Vec<(DayKey, u8, WeatherSection)>, ApiError> {
+    let mut days: BTreeMap<DayKey, (u8, WeatherSection)> = BTreeMap::new();
+
+    for period in periods {
+        let (day_key, hour, mut section) = period.transform(clock, units)?;
+
+        match days.get_mut(&day_key) {
+            None => { days.insert(day_key, (hour, section)); },
+            Some((_, day)) if !period.is_daytime => day.temp_min = day.temp_min.min(section.temp),
+            // an overnight period came first, the day replaces it
+            Some(entry) => {
+                section.temp_min = section.temp_min.min(entry.1.temp_min);
+                *entry = (hour, section);
+            },
+        }
+    }
+
+    Ok(days.into_iter().map(|(day_key, (hour, section))| (day_key, hour, section)).collect())
+}
+
+
+#[derive(Deserialize, Debug)]
+struct Points {
+    properties: PointProperties,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PointProperties {
+    forecast: String,
+    forecast_hourly: String,
+    relative_location: RelativeLocation,
+}
+
+#[derive(Deserialize, Debug)]
+struct RelativeLocation {
+    properties: RelativeProperties,
+}
+
+#[derive(Deserialize, Debug)]
+struct RelativeProperties {
+    city: String,
+    state: String,
+}
+
+impl RelativeLocation {
+    fn name(&self) -> String {
+        format!("{}, {}", self.properties.city, self.properties.state)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GridForecast {
+    properties: GridProperties,
+}
+
+impl GridForecast {
+    #[inline]
+    pub fn parse(response: &str) -> Result<GridForecast, serde_json::Error> {
+        serde_json::from_str(response)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct GridProperties {
+    periods: Vec<Period>,
+}
+
+// Quantitative value, null when the office has no data
+#[derive(Deserialize, Debug)]
+struct Measure {
+    value: Option<f32>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Period {
+    start_time: String,
+    is_daytime: bool,
+    temperature: f32,
+    probability_of_precipitation: Option<Measure>,
+    relative_humidity: Option<Measure>,
+    wind_speed: String,
+    wind_direction: String,
+    icon: String,
+    short_forecast: String,
+}
+
+impl Period {
+    #[inline]
+    fn start(&self) -> Result<DateTime<FixedOffset>, ApiError> {
+        DateTime::parse_from_rfc3339(&self.start_time).map_err(|_| ApiError::BadParse("unexpected period start time"))
+    }
+
+    fn humidity(&self) -> u16 {
+        self.relative_humidity.as_ref().and_then(|m| m.value).unwrap_or_default() as u16
+    }
+
+    // "10 mph" or "10 to 15 mph", the upper figure is used.
+    // SI speeds arrive in km/h and are converted to the m/s weathr shows
+    fn wind_speed(&self, units: Units) -> f32 {
+        let speed = self.wind_speed.split_whitespace()
+            .filter_map(|s| s.parse::<f32>().ok())
+            .fold(0.0, f32::max);
+
+        match units {
+            Units::Imperial => speed,
+            Units::Metric => speed / 3.6,
+        }
+    }
+
+    // Compass point into degrees e.g. "SW" is 225
+    fn wind_deg(&self) -> u16 {
+        const POINTS: [&str; 16] = ["N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE",
+                                    "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW"];
+
+        POINTS.iter().position(|p| *p == self.wind_direction).map(|i| (i as f32 * 22.5) as u16).unwrap_or_default()
+    }
+
+    // Icon urls carry the condition code e.g. .../icons/land/night/rain_showers,30?size=small
+    fn condition(&self) -> Condition {
+        let code = self.icon.rsplit('/').next()
+            .and_then(|s| s.split([',', '?']).next())
+            .unwrap_or_default();
+
+        nws_condition(code, &self.short_forecast, self.is_daytime)
+    }
+
+    fn transform(&self, clock: &Clock, units: Units) -> Result<(DayKey, u8, WeatherSection), ApiError> {
+        let start = self.start()?;
+        let (day_key, mut section) = WeatherSection::at(clock, start.timestamp() as u64, start.offset().local_minus_utc());
+        let condition = self.condition();
+
+        section.temp = self.temperature;
+        section.feels_like = self.temperature;
+        section.temp_min = self.temperature;
+        section.temp_max = self.temperature;
+        section.humidity = self.humidity();
+        section.pop = self.probability_of_precipitation.as_ref().and_then(|m| m.value).unwrap_or_default() / 100.0;
+        section.wind_speed = self.wind_speed(units);
+        section.wind_deg = self.wind_deg();
+        section.precip_type = Precipitation::detect(condition.id, None, None);
+        section.daytime = self.is_daytime;
+        section.conditions = vec![condition];
+
+        Ok((day_key, section.hour, section))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AlertCollection {
+    features: Vec<AlertFeature>,
+}
+
+#[derive(Deserialize, Debug)]
+struct AlertFeature {
+    properties: AlertProperties,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct AlertProperties {
+    sender_name: String,
+    event: String,
+    effective: String,
+    onset: Option<String>,
+    expires: String,
+    ends: Option<String>,
+    description: String,
+    severity: String,
+    urgency: String,
+}
+
+impl AlertCollection {
+    #[inline]
+    pub fn parse(response: &str) -> Result<AlertCollection, serde_json::Error> {
+        serde_json::from_str(response)
+    }
+
+    // Alerts without an onset are in effect from issue, those without
+    // an end last until the alert itself expires
+    pub fn transform(&self, clock: Clock) -> Vec<Alert> {
+        self.features.iter().filter_map(|f| {
+            let a = &f.properties;
+            let start = DateTime::parse_from_rfc3339(a.onset.as_ref().unwrap_or(&a.effective)).ok()?;
+            let end = DateTime::parse_from_rfc3339(a.ends.as_ref().unwrap_or(&a.expires)).ok()?;
+            let format = |value: &DateTime<FixedOffset>| clock.localize(value.timestamp() as u64, value.offset().local_minus_utc())
+                .format("%a %m-%d %H:%M").to_string();
+
+            Some(Alert {
+                sender: a.sender_name.clone(),
+                event: a.event.clone(),
+                start: start.timestamp() as u64,
+                end: end.timestamp() as u64,
+                starts: format(&start),
+                ends: format(&end),
+                description: a.description.trim().to_owned(),
+                tags: vec![a.severity.clone(), a.urgency.clone()],
+            })
+        }).collect()
+    }
+}
+
+
+// Maps an NWS icon code onto the closest OpenWeatherMap
+// condition id, group and icon, the short forecast is the description
+fn nws_condition(code: &str, short_forecast: &str, daytime: bool) -> Condition {
+    let (id, group, icon) = match code {
+        "skc" | "hot" | "cold" => (800, "Clear", "01"),
+        "few" => (801, "Clouds", "02"),
+        "sct" => (802, "Clouds", "03"),
+        "bkn" => (803, "Clouds", "04"),
+        "ovc" => (804, "Clouds", "04"),
+        c if c.starts_with("wind_") => (771, "Squall", "50"),
+        "snow" | "blizzard" => (601, "Snow", "13"),
+        "rain_snow" | "rain_sleet" | "snow_sleet" | "sleet" => (616, "Snow", "13"),
+        "fzra" | "rain_fzra" | "snow_fzra" => (511, "Rain", "13"),
+        "rain" => (501, "Rain", "10"),
+        "rain_showers" | "rain_showers_hi" => (521, "Rain", "09"),
+        "tsra" | "tsra_sct" | "tsra_hi" => (211, "Thunderstorm", "11"),
+        "tornado" => (781, "Tornado", "50"),
+        "hurricane" | "tropical_storm" => (771, "Squall", "50"),
+        "dust" => (761, "Dust", "50"),
+        "smoke" => (711, "Smoke", "50"),
+        "haze" => (721, "Haze", "50"),
+        "fog" => (741, "Fog", "50"),
+        _ => (800, "Unknown", "01"),
+    };
+
+    Condition {
+        id,
+        group: group.to_owned(),
+        description: short_forecast.to_lowercase(),
+        icon: format!("{}{}", icon, if daytime { "d" } else { "n" }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+
+    use super::*;
+    use crate::mock_json::{JSON_NWS_ALERTS_RESPONSE, JSON_NWS_FORECAST_RESPONSE, JSON_NWS_HOURLY_RESPONSE, JSON_NWS_POINTS_RESPONSE};
+
+    fn points() -> PointProperties {
+        serde_json::from_str::<Points>(JSON_NWS_POINTS_RESPONSE).unwrap().properties
+    }
+
+    fn place() -> Place {
+        Place { name: String::from("Tucson"), country_code: Some(String::from("US")), latitude: 32.2217, longitude: -110.9265, timezone: None }
+    }
+
+    fn periods(response: &str) -> Vec<Period> {
+        GridForecast::parse(response).unwrap().properties.periods
+    }
+
+    #[test]
+    fn maps_hourly_periods_into_sections() {
+        let hourly = periods(JSON_NWS_HOURLY_RESPONSE);
+        let (day, hour, section) = hourly[2].transform(&Clock::City, Units::Imperial).unwrap();
+
+        assert_eq!(hourly.len(), 36);
+        assert_eq!((day.to_string(), hour), (String::from("2022-06-18"), 19));
+        assert_eq!((section.temp, section.pop, section.wind_speed, section.wind_deg), (89.0, 0.4, 15.0, 270));
+        assert_eq!((section.conditions[0].id, section.precip_type), (211, Precipitation::Rain));
+        assert_eq!(section.conditions[0].icon, "11n");
+        assert!(!section.daytime);
+
+        let (_, _, metric) = hourly[2].transform(&Clock::City, Units::Metric).unwrap();
+        assert!((metric.wind_speed - 15.0 / 3.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn merges_day_and_night_periods_into_daily() {
+        let days = daily(&periods(JSON_NWS_FORECAST_RESPONSE), &Clock::City, Units::Imperial).unwrap();
+        let summary: Vec<(String, u8, f32, f32)> = days.iter()
+            .map(|(day, hour, s)| (day.to_string(), *hour, s.temp_min, s.temp_max))
+            .collect();
+
+        // the night starting at 18:00 belongs to the day it starts on
+        assert_eq!(summary, [
+            (String::from("2022-06-18"), 15, 77.0, 96.0),
+            (String::from("2022-06-19"), 6, 76.0, 94.0),
+            (String::from("2022-06-20"), 6, 95.0, 95.0),
+        ]);
+        assert_eq!(days[0].2.conditions[0].description, "chance showers and thunderstorms");
+        assert!(days.iter().all(|(_, _, s)| s.daytime));
+    }
+
+    #[test]
+    fn works_out_daylight_for_the_place() {
+        let city = city(&points(), &place(), &periods(JSON_NWS_HOURLY_RESPONSE));
+
+        // isDaytime flips at 05:00 and 19:00, the sun rises at 05:17 and sets at 19:32
+        // give or take the minute the sunrise equation allows
+        assert_eq!(city.describe(&Clock::City), "Tucson, AZ US [32.2217, -110.9265]\nSunrise 06-18 05:18  Sunset 06-18 19:33  (city time)");
+    }
+
+    #[test]
+    fn takes_current_conditions_from_the_first_hour() {
+        let current = current(&points(), &periods(JSON_NWS_HOURLY_RESPONSE), Units::Imperial, Clock::City).unwrap();
+
+        assert_eq!(current.place, "Tucson, AZ");
+        assert_eq!(current.datetime, "Sat 06-18 17:00");
+        assert_eq!((current.temp, current.temp_min, current.temp_max), (93.0, 74.0, 94.0));
+        assert_eq!((current.wind_speed, current.wind_deg), (5.0, 225));
+    }
+
+    #[test]
+    fn maps_alerts_onto_the_clock() {
+        let alerts = AlertCollection::parse(JSON_NWS_ALERTS_RESPONSE).unwrap().transform(Clock::Utc);
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].event, "Excessive Heat Warning");
+        // onset and ends win over effective and expires
+        assert_eq!((alerts[0].starts.as_str(), alerts[0].ends.as_str()), ("Sat 06-18 17:00", "Mon 06-20 03:00"));
+        assert_eq!(alerts[0].end - alerts[0].start, 34 * 3600);
+        assert_eq!(alerts[0].tags, ["Severe", "Expected"]);
+        assert!(alerts[0].description.ends_with("Tucson Metro Area."));
+        assert_eq!(Clock::City.localize(alerts[0].start, -7 * 3600).hour(), 10);
+    }
+}
@@ -0,0 +1,330 @@
+use serde::Deserialize;
+
+use crate::backend::{group_days, City, Clock, Condition, DayKey, Granularity, Precipitation};
+use crate::display::{Alert, WeatherForecast, WeatherSection};
+use crate::filter::HourFilter;
+use crate::request::ApiError;
+
+/*
+   Snippet of One Call 3.0 JSON data
+
+   timezone_offset: -25200,
+   minutely: [{ dt: 1655586060, precipitation: 0.21 }, ...],
+   hourly: [{
+     dt: 1655586000, temp: 92.41, feels_like: 90.88, pressure: 1011,
+     humidity: 30, clouds: 75, visibility: 10000, wind_speed: 21.43,
+     wind_deg: 236, wind_gust: 21.74, weather: [...], pop: 0.45,
+     rain: { 1h: 0.22 }
+   }, ...],
+   daily: [{
+     dt: 1655575200, sunrise: 1655554625, sunset: 1655605961,
+     temp: { day: 92.4, min: 77.5, max: 96.1, night: 81.2, eve: 90.3, morn: 78.0 },
+     feels_like: { day: 90.9, night: 81.3, eve: 87.3, morn: 78.1 },
+     pressure: 1011, humidity: 30, wind_speed: 21.43, wind_deg: 236,
+     wind_gust: 21.74, weather: [...], clouds: 75, pop: 0.45, rain: 0.94
+   }, ...],
+   alerts: [{
+     sender_name: "NWS Tucson (Southeast Arizona)",
+     event: "Excessive Heat Warning",
+     start: 1655571600, end: 1655604000,
+     description: "...", tags: ["Extreme temperature value"]
+   }]
+*/
+
+/*
+  File contains raw One Call structs that directly map to json parse,
+  each granularity flattens into the shared WeatherSection
+*/
+
+#[derive(Deserialize, Debug)]
+pub struct OneCall {
+    timezone_offset: i32,
+    #[serde(default)]
+    minutely: Vec<MinutelyData>,
+    #[serde(default)]
+    hourly: Vec<HourlyData>,
+    #[serde(default)]
+    daily: Vec<DailyData>,
+    #[serde(default)]
+    alerts: Vec<AlertData>,
+}
+
+impl OneCall {
+
+    #[inline]
+    pub fn parse(response: &str) -> Result<OneCall, serde_json::Error> {
+        serde_json::from_str(response)
+    }
+
+    // Government weather alerts shown on the given clock
+    pub fn alerts(&self, clock: Clock) -> Vec<Alert> {
+        self.alerts.iter().map(|a| a.transform(&clock, self.timezone_offset)).collect()
+    }
+
+    // transforms the requested granularity into weather forecast,
+    // city supplies the header since One Call has no place name.
+    // Only hourly data is hour filtered, 3h data comes from WeatherList
+    pub fn transform(&self, granularity: Granularity, city: City, clock: Clock, filter: &HourFilter) -> Result<WeatherForecast, ApiError> {
+        let offset = self.timezone_offset;
+
+        let (list, filter) = match granularity {
+            Granularity::Minutely => (self.minutely.iter().map(|m| m.transform(&clock, offset)).collect(), None),
+            Granularity::Hourly => (self.hourly.iter().map(|h| h.transform(&clock, offset)).collect::<Result<Vec<_>, _>>()?, Some(filter)),
+            Granularity::Daily => (self.daily.iter().map(|d| d.transform(&clock, offset)).collect::<Result<Vec<_>, _>>()?, None),
+            Granularity::ThreeHour => return Err(ApiError::BadParse("3h forecasts come from the forecast endpoint")),
+        };
+
+        let map = group_days(list, filter, city.daylight_hours(&clock));
+
+        Ok(WeatherForecast::new(city, clock, map)
+           .with_granularity(granularity)
+           .with_alerts(self.alerts(clock)))
+    }
+}
+
+// Precipitation volume over the last hour
+#[derive(Deserialize, Debug)]
+struct HourVolume {
+    #[serde(rename = "1h")]
+    one_hour: f32,
+}
+
+// Precipitation (mm/h) for each minute of the next hour
+#[derive(Deserialize, Debug)]
+struct MinutelyData {
+    #[serde(rename = "dt")]
+    datetime: u64,
+    precipitation: f32,
+}
+
+impl MinutelyData {
+    fn transform(&self, clock: &Clock, city_offset: i32) -> (DayKey, u8, WeatherSection) {
+        let (day_key, mut section) = WeatherSection::at(clock, self.datetime, city_offset);
+
+        // a rate in mm/h, so amounts as if over an hour
+        section.precipitation = self.precipitation;
+        section.accumulation = Some(3600);
+
+        (day_key, section.hour, section)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct HourlyData {
+    #[serde(rename = "dt")]
+    datetime: u64,
+    temp: f32,
+    feels_like: f32,
+    pressure: u16,
+    humidity: u16,
+    clouds: u8,
+    visibility: Option<u32>,
+    wind_speed: f32,
+    wind_deg: u16,
+    wind_gust: Option<f32>,
+    weather: Vec<Condition>,
+    #[serde(default)]
+    pop: f32,
+    rain: Option<HourVolume>,
+    snow: Option<HourVolume>,
+}
+
+impl HourlyData {
+    fn transform(&self, clock: &Clock, city_offset: i32) -> Result<(DayKey, u8, WeatherSection), ApiError> {
+        let (day_key, mut section) = WeatherSection::at(clock, self.datetime, city_offset);
+        let primary = self.weather.first().ok_or_else(|| ApiError::NoConditions(section.dt_txt.clone()))?;
+        let rain = self.rain.as_ref().map(|r| r.one_hour);
+        let snow = self.snow.as_ref().map(|s| s.one_hour);
+
+        section.temp = self.temp;
+        section.feels_like = self.feels_like;
+        section.temp_min = self.temp;
+        section.temp_max = self.temp;
+        section.pressure = self.pressure;
+        section.humidity = self.humidity;
+        section.daytime = primary.icon.ends_with('d');
+        section.conditions = self.weather.clone();
+        section.clouds = self.clouds;
+        section.visibility = self.visibility;
+        section.pop = self.pop;
+        section.wind_speed = self.wind_speed;
+        section.wind_deg = self.wind_deg;
+        section.wind_gust = self.wind_gust;
+        section.rain_volume = rain;
+        section.snow_volume = snow;
+        section.precipitation = rain.unwrap_or_default() + snow.unwrap_or_default();
+        section.accumulation = Some(3600);
+        section.precip_type = Precipitation::detect(primary.id, rain, snow);
+
+        Ok((day_key, section.hour, section))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct DailyData {
+    #[serde(rename = "dt")]
+    datetime: u64,
+    temp: DailyTemperature,
+    feels_like: DailyFeelsLike,
+    pressure: u16,
+    humidity: u16,
+    wind_speed: f32,
+    wind_deg: u16,
+    wind_gust: Option<f32>,
+    weather: Vec<Condition>,
+    clouds: u8,
+    #[serde(default)]
+    pop: f32,
+    rain: Option<f32>,
+    snow: Option<f32>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DailyTemperature {
+    day: f32,
+    min: f32,
+    max: f32,
+}
+
+#[derive(Deserialize, Debug)]
+struct DailyFeelsLike {
+    day: f32,
+}
+
+impl DailyData {
+    fn transform(&self, clock: &Clock, city_offset: i32) -> Result<(DayKey, u8, WeatherSection), ApiError> {
+        let (day_key, mut section) = WeatherSection::at(clock, self.datetime, city_offset);
+        let primary = self.weather.first().ok_or_else(|| ApiError::NoConditions(section.dt_txt.clone()))?;
+
+        section.temp = self.temp.day;
+        section.feels_like = self.feels_like.day;
+        section.temp_min = self.temp.min;
+        section.temp_max = self.temp.max;
+        section.pressure = self.pressure;
+        section.humidity = self.humidity;
+        section.daytime = true;
+        section.conditions = self.weather.clone();
+        section.clouds = self.clouds;
+        section.pop = self.pop;
+        section.wind_speed = self.wind_speed;
+        section.wind_deg = self.wind_deg;
+        section.wind_gust = self.wind_gust;
+        section.rain_volume = self.rain;
+        section.snow_volume = self.snow;
+        section.precipitation = self.rain.unwrap_or_default() + self.snow.unwrap_or_default();
+        section.accumulation = Some(24 * 3600);
+        section.precip_type = Precipitation::detect(primary.id, self.rain, self.snow);
+
+        Ok((day_key, section.hour, section))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct AlertData {
+    sender_name: String,
+    event: String,
+    start: u64,
+    end: u64,
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl AlertData {
+    fn transform(&self, clock: &Clock, city_offset: i32) -> Alert {
+        let format = |value| clock.localize(value, city_offset).format("%a %m-%d %H:%M").to_string();
+
+        Alert {
+            sender: self.sender_name.clone(),
+            event: self.event.clone(),
+            start: self.start,
+            end: self.end,
+            starts: format(self.start),
+            ends: format(self.end),
+            description: self.description.trim().to_owned(),
+            tags: self.tags.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+
+    use super::*;
+    use crate::backend::CurrentWeather;
+    use crate::filter::Preset;
+    use crate::mock_json::{JSON_CURRENT_RESPONSE, JSON_ONE_CALL_RESPONSE};
+
+    fn forecast(granularity: Granularity, filter: HourFilter) -> WeatherForecast {
+        let city = CurrentWeather::parse(JSON_CURRENT_RESPONSE).unwrap().city();
+
+        OneCall::parse(JSON_ONE_CALL_RESPONSE).unwrap()
+            .transform(granularity, city, Clock::City, &filter)
+            .unwrap()
+    }
+
+    #[test]
+    fn maps_minutely_precipitation() {
+        let forecast = forecast(Granularity::Minutely, HourFilter::from(Preset::Daytime));
+        let minutes: Vec<(String, f32)> = forecast.sections()
+            .map(|(_, s)| (s.local.format("%H:%M").to_string(), s.precipitation))
+            .collect();
+
+        assert_eq!(forecast.granularity(), Granularity::Minutely);
+        assert_eq!(minutes.len(), 6);
+        assert_eq!(minutes[0], (String::from("13:13"), 0.0));
+        assert_eq!(minutes[4], (String::from("13:17"), 0.4));
+    }
+
+    #[test]
+    fn maps_hourly_sections_through_the_filter() {
+        let all = forecast(Granularity::Hourly, HourFilter::from(Preset::All));
+        let hours: Vec<u32> = all.sections().map(|(_, s)| s.local.hour()).collect();
+        let first = all.sections().next().unwrap().1;
+
+        assert_eq!(hours, [14, 15, 16, 17, 18, 19]);
+        assert_eq!((first.temp, first.temp_min, first.temp_max), (92.4, 92.4, 92.4));
+        assert_eq!((first.rain_volume, first.precip_type), (Some(0.22), Precipitation::Rain));
+        assert!(first.daytime);
+        assert!(!all.sections().last().unwrap().1.daytime);
+
+        let daytime = forecast(Granularity::Hourly, HourFilter::from(Preset::Daytime));
+        assert_eq!(daytime.sections().count(), 5);
+    }
+
+    #[test]
+    fn maps_daily_sections_one_per_day() {
+        let forecast = forecast(Granularity::Daily, HourFilter::from(Preset::Night));
+        let days: Vec<(String, f32, f32)> = forecast.sections()
+            .map(|(day, s)| (day.to_string(), s.temp_min, s.temp_max))
+            .collect();
+
+        assert_eq!(days, [
+            (String::from("2022-06-18"), 77.5, 96.1),
+            (String::from("2022-06-19"), 76.5, 97.1),
+            (String::from("2022-06-20"), 75.5, 98.1),
+        ]);
+        assert_eq!(forecast.sections().next().unwrap().1.precipitation, 0.94);
+    }
+
+    #[test]
+    fn carries_alerts_on_the_clock() {
+        let forecast = forecast(Granularity::Hourly, HourFilter::from(Preset::All));
+        let alert = &forecast.alerts()[0];
+
+        assert_eq!(alert.event, "Excessive Heat Warning");
+        assert_eq!((alert.starts.as_str(), alert.ends.as_str()), ("Sat 06-18 10:00", "Sat 06-18 19:00"));
+        assert!(forecast.has_active_alert(1655590000));
+        assert!(!forecast.has_active_alert(1655604000));
+    }
+
+    #[test]
+    fn leaves_three_hour_to_the_forecast_endpoint() {
+        let city = CurrentWeather::parse(JSON_CURRENT_RESPONSE).unwrap().city();
+        let result = OneCall::parse(JSON_ONE_CALL_RESPONSE).unwrap()
+            .transform(Granularity::ThreeHour, city, Clock::City, &HourFilter::default());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,559 @@
+use serde::Deserialize;
+use url::Url;
+
+use crate::backend::{group_days, three_hourly, City, Clock, Condition, DayKey, Granularity, Precipitation, Span};
+use crate::display::{CurrentConditions, WeatherForecast, WeatherSection};
+use crate::http::HttpClient;
+use crate::provider::{Query, WeatherProvider};
+use crate::request::{ApiError, Location, Units};
+
+// Open-Meteo provider, free and keyless. Forecast data arrives as
+// parallel arrays per variable which are zipped into WeatherSections.
+// WMO weather codes are mapped onto OpenWeatherMap condition ids so
+// precipitation detection and icons behave the same across providers.
+
+/*
+   Snippet of JSON data (timeformat=unixtime)
+
+   utc_offset_seconds: -25200,
+   current: { time: 1655583300, temperature_2m: 91.6, apparent_temperature: 89.9, ... },
+   hourly: {
+     time: [1655586000, ...],
+     temperature_2m: [92.4, ...],
+     apparent_temperature: [90.9, ...],
+     relative_humidity_2m: [30, ...],
+     precipitation: [0.2, ...],
+     rain: [0.2, ...],
+     snowfall: [0.0, ...],
+     wind_speed_10m: [21.4, ...],
+     wind_direction_10m: [236, ...],
+     is_day: [1, ...],
+     weathercode: [61, ...]
+   },
+   daily: { time: [...], sunrise: [...], sunset: [...], temperature_2m_max: [...], ... }
+*/
+
+const BASE_API_URL: &str = "https://api.open-meteo.com/v1/forecast";
+pub(crate) const GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+// the forecast endpoint asked for no variables is a timezone lookup
+pub(crate) const TIMEZONE_URL: &str = BASE_API_URL;
+const FORECAST_DAYS: &str = "5";
+
+const HOURLY: &str = "temperature_2m,apparent_temperature,relative_humidity_2m,precipitation,rain,snowfall,\
+                      wind_speed_10m,wind_direction_10m,is_day,weathercode";
+const DAILY: &str = "weathercode,temperature_2m_max,temperature_2m_min,apparent_temperature_max,\
+                     precipitation_sum,rain_sum,snowfall_sum,wind_speed_10m_max,wind_direction_10m_dominant,sunrise,sunset";
+// snowfall comes in centimetres whatever the precipitation unit
+const MM_PER_CM: f32 = 10.0;
+const CURRENT: &str = "temperature_2m,apparent_temperature,relative_humidity_2m,precipitation,rain,snowfall,\
+                       weathercode,cloud_cover,pressure_msl,wind_speed_10m,wind_direction_10m,wind_gusts_10m";
+
+#[derive(Debug)]
+pub struct OpenMeteo {
+    base_url: String,
+    geocoding_url: String,
+    http: HttpClient,
+}
+
+impl Default for OpenMeteo {
+    fn default() -> Self { OpenMeteo::new() }
+}
+
+impl OpenMeteo {
+    pub fn new() -> OpenMeteo {
+        OpenMeteo {
+            base_url: BASE_API_URL.to_owned(),
+            geocoding_url: GEOCODING_URL.to_owned(),
+            http: HttpClient::new(),
+        }
+    }
+
+    // Point at different hosts e.g. a local stand-in server
+    pub fn with_base_url(mut self, base_url: &str, geocoding_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self.geocoding_url = geocoding_url.to_owned();
+        self
+    }
+
+    pub fn with_http(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    // Place name and coordinates, names are resolved with the Open-Meteo geocoder
+    fn place(&self, query: &Query) -> Result<Place, ApiError> {
+        locate(&self.http, &self.geocoding_url, query)
+    }
+
+    fn request(&self, place: &Place, units: Units) -> Result<ForecastData, ApiError> {
+        let (temperature, wind) = match units {
+            Units::Imperial => ("fahrenheit", "mph"),
+            Units::Metric => ("celsius", "ms"),
+        };
+
+        let mut url = Url::parse(&self.base_url)?;
+        url.query_pairs_mut()
+            .append_pair("latitude", &place.latitude.to_string())
+            .append_pair("longitude", &place.longitude.to_string())
+            .append_pair("hourly", HOURLY)
+            .append_pair("daily", DAILY)
+            .append_pair("current", CURRENT)
+            .append_pair("temperature_unit", temperature)
+            .append_pair("wind_speed_unit", wind)
+            .append_pair("precipitation_unit", "mm")
+            .append_pair("timeformat", "unixtime")
+            .append_pair("timezone", "auto")
+            .append_pair("forecast_days", FORECAST_DAYS);
+
+        let response = self.http.call(self.http.get(url.as_str()))?.into_string()?;
+
+        Ok(ForecastData::parse(&response)?)
+    }
+}
+
+impl WeatherProvider for OpenMeteo {
+    fn name(&self) -> &'static str {
+        "open-meteo"
+    }
+
+    fn current(&self, query: &Query) -> Result<CurrentConditions, ApiError> {
+        let place = self.place(query)?;
+        let data = self.request(&place, query.units)?;
+
+        data.current(&place, query.clock)
+    }
+
+    fn forecast(&self, query: &Query) -> Result<WeatherForecast, ApiError> {
+        let place = self.place(query)?;
+        let data = self.request(&place, query.units)?;
+        let current = data.current(&place, query.clock)?;
+
+        Ok(data.transform(&place, query)?.with_current(current))
+    }
+}
+
+
+// Place name and coordinates for the query location, names are resolved
+// with the keyless Open-Meteo geocoder which other providers share
+pub(crate) fn locate(http: &HttpClient, geocoding_url: &str, query: &Query) -> Result<Place, ApiError> {
+    let name = match query.location {
+        Location::Coordinates(lat, lon) => {
+            return Ok(Place { name: format!("{:.4}, {:.4}", lat, lon), country_code: None, latitude: lat, longitude: lon, timezone: None })
+        },
+        Location::City(ref city) => city.clone(),
+        Location::CityCountry(ref city, _) | Location::CityStateCountry(ref city, _, _) => city.clone(),
+        Location::Zip(..) | Location::Id(_) => return Err(ApiError::Unsupported("open-meteo", "zip or city id locations")),
+    };
+
+    let mut url = Url::parse(geocoding_url)?;
+    url.query_pairs_mut()
+        .append_pair("name", &name)
+        .append_pair("count", "10");
+
+    let response = http.call(http.get(url.as_str()))?.into_string()?;
+    let results: GeoResults = serde_json::from_str(&response)?;
+
+    // narrow by country code when given
+    let country = match query.location {
+        Location::CityCountry(_, ref c) | Location::CityStateCountry(_, _, ref c) => Some(c.to_uppercase()),
+        _ => None,
+    };
+
+    let mut candidates: Vec<Place> = results.results.into_iter()
+        .filter(|p| country.as_ref().is_none_or(|c| p.country_code.as_ref() == Some(c)))
+        .collect();
+
+    match (candidates.len(), query.pick) {
+        (0, _) => Err(ApiError::NoMatches(query.location.to_string())),
+        (n, Some(p)) if p == 0 || p > n => Err(ApiError::BadPick(p, n)),
+        (_, Some(p)) => Ok(candidates.swap_remove(p - 1)),
+        (_, None) => Ok(candidates.swap_remove(0)),
+    }
+}
+
+// IANA timezone at the coordinates, which the geocoder only gives for
+// places it resolves. Open-Meteo answers with "GMT" out at sea
+pub(crate) fn timezone(http: &HttpClient, timezone_url: &str, latitude: f64, longitude: f64) -> Result<Option<String>, ApiError> {
+    let mut url = Url::parse(timezone_url)?;
+    url.query_pairs_mut()
+        .append_pair("latitude", &latitude.to_string())
+        .append_pair("longitude", &longitude.to_string())
+        .append_pair("timezone", "auto")
+        .append_pair("forecast_days", "1");
+
+    let response = http.call(http.get(url.as_str()))?.into_string()?;
+    let zone: Zone = serde_json::from_str(&response)?;
+
+    Ok(zone.timezone)
+}
+
+
+#[derive(Deserialize, Debug)]
+struct Zone {
+    timezone: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GeoResults {
+    #[serde(default)]
+    results: Vec<Place>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct Place {
+    pub(crate) name: String,
+    pub(crate) country_code: Option<String>,
+    pub(crate) latitude: f64,
+    pub(crate) longitude: f64,
+    pub(crate) timezone: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ForecastData {
+    utc_offset_seconds: i32,
+    current: Option<CurrentData>,
+    hourly: HourlyData,
+    daily: DailyData,
+}
+
+#[derive(Deserialize, Debug)]
+struct CurrentData {
+    time: u64,
+    temperature_2m: f32,
+    apparent_temperature: f32,
+    relative_humidity_2m: u16,
+    rain: Option<f32>,
+    snowfall: Option<f32>,
+    weathercode: u16,
+    cloud_cover: Option<u8>,
+    pressure_msl: Option<f32>,
+    wind_speed_10m: f32,
+    wind_direction_10m: u16,
+    wind_gusts_10m: Option<f32>,
+}
+
+#[derive(Deserialize, Debug)]
+struct HourlyData {
+    time: Vec<u64>,
+    temperature_2m: Vec<f32>,
+    apparent_temperature: Vec<f32>,
+    relative_humidity_2m: Vec<u16>,
+    precipitation: Vec<f32>,
+    #[serde(default)]
+    rain: Vec<f32>,
+    #[serde(default)]
+    snowfall: Vec<f32>,
+    wind_speed_10m: Vec<f32>,
+    wind_direction_10m: Vec<u16>,
+    is_day: Vec<u8>,
+    weathercode: Vec<u16>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DailyData {
+    time: Vec<u64>,
+    weathercode: Vec<u16>,
+    temperature_2m_max: Vec<f32>,
+    temperature_2m_min: Vec<f32>,
+    apparent_temperature_max: Vec<f32>,
+    precipitation_sum: Vec<f32>,
+    #[serde(default)]
+    rain_sum: Vec<f32>,
+    #[serde(default)]
+    snowfall_sum: Vec<f32>,
+    wind_speed_10m_max: Vec<f32>,
+    wind_direction_10m_dominant: Vec<u16>,
+    sunrise: Vec<u64>,
+    sunset: Vec<u64>,
+}
+
+impl ForecastData {
+
+    #[inline]
+    pub fn parse(response: &str) -> Result<ForecastData, serde_json::Error> {
+        serde_json::from_str(response)
+    }
+
+    fn city(&self, place: &Place) -> City {
+        City::new(
+            place.name.clone(),
+            place.country_code.clone().unwrap_or_default(),
+            place.latitude,
+            place.longitude,
+            self.daily.sunrise.first().copied().unwrap_or_default(),
+            self.daily.sunset.first().copied().unwrap_or_default(),
+            self.utc_offset_seconds,
+        )
+    }
+
+    fn current(&self, place: &Place, clock: Clock) -> Result<CurrentConditions, ApiError> {
+        let c = self.current.as_ref().ok_or(ApiError::Unsupported("open-meteo", "current conditions for this location"))?;
+        let datetime = clock.localize(c.time, self.utc_offset_seconds);
+        let condition = wmo_condition(c.weathercode, true);
+
+        Ok(CurrentConditions {
+            place: format!("{} {}", place.name, place.country_code.as_deref().unwrap_or_default()).trim().to_owned(),
+            datetime: datetime.format("%a %m-%d %H:%M").to_string(),
+            temp: c.temperature_2m,
+            feels_like: c.apparent_temperature,
+            temp_min: self.daily.temperature_2m_min.first().copied().unwrap_or(c.temperature_2m),
+            temp_max: self.daily.temperature_2m_max.first().copied().unwrap_or(c.temperature_2m),
+            pressure: c.pressure_msl.unwrap_or_default().round() as u16,
+            humidity: c.relative_humidity_2m,
+            conditions: vec![condition],
+            clouds: c.cloud_cover.unwrap_or_default(),
+            visibility: None,
+            wind_speed: c.wind_speed_10m,
+            wind_deg: c.wind_direction_10m,
+            wind_gust: c.wind_gusts_10m,
+            rain_one_hour: c.rain.filter(|r| *r > 0.0),
+            snow_one_hour: c.snowfall.map(|s| s * MM_PER_CM).filter(|s| *s > 0.0),
+            air_quality: None,
+        })
+    }
+
+    // transforms hourly (or daily) arrays into weather forecast, 3h rolls
+    // up the hours to match the OpenWeatherMap layout. Hourly amounts
+    // are sums over the preceding hour
+    fn transform(&self, place: &Place, query: &Query) -> Result<WeatherForecast, ApiError> {
+        let clock = query.clock;
+        let city = self.city(place);
+
+        let (list, filter) = match query.granularity {
+            Granularity::Minutely => return Err(ApiError::Unsupported("open-meteo", "minutely forecasts")),
+            Granularity::Daily => ((0..self.daily.time.len()).map(|i| self.daily_section(i, &clock)).collect::<Result<_, _>>()?, None),
+            Granularity::Hourly => ((0..self.hourly.time.len()).map(|i| self.hourly_section(i, &clock)).collect::<Result<_, _>>()?, Some(&query.filter)),
+            Granularity::ThreeHour => {
+                let list: Vec<(DayKey, u8, WeatherSection, Span)> = (0..self.hourly.time.len())
+                    .map(|i| {
+                        let (day, hour, ws) = self.hourly_section(i, &clock)?;
+                        let span = (ws.timestamp.saturating_sub(3600), ws.timestamp);
+                        Ok((day, hour, ws, span))
+                    })
+                    .collect::<Result<_, ApiError>>()?;
+                (three_hourly(list), Some(&query.filter))
+            },
+        };
+
+        let map = group_days(list, filter, city.daylight_hours(&clock));
+
+        Ok(WeatherForecast::new(city, clock, map).with_granularity(query.granularity))
+    }
+
+    // Variables arrive as parallel arrays, a short one is a malformed response
+    fn hourly_section(&self, i: usize, clock: &Clock) -> Result<(DayKey, u8, WeatherSection), ApiError> {
+        let h = &self.hourly;
+        let (day_key, mut section) = WeatherSection::at(clock, nth(&h.time, i, "hourly time")?, self.utc_offset_seconds);
+        let rain = h.rain.get(i).copied().filter(|r| *r > 0.0);
+        let snow = h.snowfall.get(i).map(|s| s * MM_PER_CM).filter(|s| *s > 0.0);
+        let condition = wmo_condition(nth(&h.weathercode, i, "hourly weathercode")?, nth(&h.is_day, i, "hourly is_day")? == 1);
+        let temp = nth(&h.temperature_2m, i, "hourly temperature_2m")?;
+
+        section.temp = temp;
+        section.feels_like = nth(&h.apparent_temperature, i, "hourly apparent_temperature")?;
+        section.temp_min = temp;
+        section.temp_max = temp;
+        section.humidity = nth(&h.relative_humidity_2m, i, "hourly relative_humidity_2m")?;
+        section.wind_speed = nth(&h.wind_speed_10m, i, "hourly wind_speed_10m")?;
+        section.wind_deg = nth(&h.wind_direction_10m, i, "hourly wind_direction_10m")?;
+        section.rain_volume = rain;
+        section.snow_volume = snow;
+        section.precipitation = nth(&h.precipitation, i, "hourly precipitation")?;
+        section.accumulation = Some(3600);
+        section.precip_type = Precipitation::detect(condition.id, rain, snow);
+        section.daytime = condition.icon.ends_with('d');
+        section.conditions = vec![condition];
+
+        Ok((day_key, section.hour, section))
+    }
+
+    fn daily_section(&self, i: usize, clock: &Clock) -> Result<(DayKey, u8, WeatherSection), ApiError> {
+        let d = &self.daily;
+        let (day_key, mut section) = WeatherSection::at(clock, nth(&d.time, i, "daily time")?, self.utc_offset_seconds);
+        let rain = d.rain_sum.get(i).copied().filter(|r| *r > 0.0);
+        let snow = d.snowfall_sum.get(i).map(|s| s * MM_PER_CM).filter(|s| *s > 0.0);
+        let condition = wmo_condition(nth(&d.weathercode, i, "daily weathercode")?, true);
+        let max = nth(&d.temperature_2m_max, i, "daily temperature_2m_max")?;
+
+        section.temp = max;
+        section.feels_like = nth(&d.apparent_temperature_max, i, "daily apparent_temperature_max")?;
+        section.temp_min = nth(&d.temperature_2m_min, i, "daily temperature_2m_min")?;
+        section.temp_max = max;
+        section.wind_speed = nth(&d.wind_speed_10m_max, i, "daily wind_speed_10m_max")?;
+        section.wind_deg = nth(&d.wind_direction_10m_dominant, i, "daily wind_direction_10m_dominant")?;
+        section.rain_volume = rain;
+        section.snow_volume = snow;
+        section.precipitation = nth(&d.precipitation_sum, i, "daily precipitation_sum")?;
+        section.accumulation = Some(24 * 3600);
+        section.precip_type = Precipitation::detect(condition.id, rain, snow);
+        section.daytime = true;
+        section.conditions = vec![condition];
+
+        Ok((day_key, section.hour, section))
+    }
+}
+
+// Value at i of a variable's array, which should be as long as its time array
+#[inline]
+fn nth<T: Copy>(values: &[T], i: usize, variable: &str) -> Result<T, ApiError> {
+    values.get(i).copied().ok_or_else(|| ApiError::Parse("open-meteo", format!("{} has no value {}", variable, i)))
+}
+
+
+// Maps a WMO weather interpretation code onto the closest
+// OpenWeatherMap condition id, group, description and icon
+fn wmo_condition(code: u16, daytime: bool) -> Condition {
+    let (id, group, description, icon) = match code {
+        0 => (800, "Clear", "clear sky", "01"),
+        1 => (801, "Clouds", "mainly clear", "02"),
+        2 => (802, "Clouds", "partly cloudy", "03"),
+        3 => (804, "Clouds", "overcast", "04"),
+        45 => (741, "Fog", "fog", "50"),
+        48 => (741, "Fog", "depositing rime fog", "50"),
+        51 => (300, "Drizzle", "light drizzle", "09"),
+        53 => (301, "Drizzle", "drizzle", "09"),
+        55 => (302, "Drizzle", "dense drizzle", "09"),
+        56 | 57 => (511, "Rain", "freezing drizzle", "13"),
+        61 => (500, "Rain", "slight rain", "10"),
+        63 => (501, "Rain", "moderate rain", "10"),
+        65 => (502, "Rain", "heavy rain", "10"),
+        66 | 67 => (511, "Rain", "freezing rain", "13"),
+        71 => (600, "Snow", "slight snow", "13"),
+        73 => (601, "Snow", "moderate snow", "13"),
+        75 => (602, "Snow", "heavy snow", "13"),
+        77 => (600, "Snow", "snow grains", "13"),
+        80 => (520, "Rain", "slight rain showers", "09"),
+        81 => (521, "Rain", "moderate rain showers", "09"),
+        82 => (522, "Rain", "violent rain showers", "09"),
+        85 => (620, "Snow", "slight snow showers", "13"),
+        86 => (621, "Snow", "heavy snow showers", "13"),
+        95 => (211, "Thunderstorm", "thunderstorm", "11"),
+        96 | 99 => (202, "Thunderstorm", "thunderstorm with hail", "11"),
+        _ => (800, "Unknown", "unknown", "01"),
+    };
+
+    Condition {
+        id,
+        group: group.to_owned(),
+        description: description.to_owned(),
+        icon: format!("{}{}", icon, if daytime { "d" } else { "n" }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+
+    use super::*;
+    use crate::filter::{HourFilter, Preset};
+    use crate::mock_json::JSON_OPEN_METEO_RESPONSE;
+
+    fn place() -> Place {
+        Place { name: String::from("Tucson"), country_code: Some(String::from("US")), latitude: 32.2217, longitude: -110.9265, timezone: None }
+    }
+
+    fn query(granularity: Granularity) -> Query {
+        let mut query = Query::new(Location::Coordinates(32.2217, -110.9265), Units::Imperial);
+        query.granularity = granularity;
+        query.filter = HourFilter::from(Preset::All);
+        query
+    }
+
+    fn forecast(granularity: Granularity) -> WeatherForecast {
+        ForecastData::parse(JSON_OPEN_METEO_RESPONSE).unwrap().transform(&place(), &query(granularity)).unwrap()
+    }
+
+    #[test]
+    fn maps_current_conditions() {
+        let current = ForecastData::parse(JSON_OPEN_METEO_RESPONSE).unwrap().current(&place(), Clock::City).unwrap();
+
+        assert_eq!(current.place, "Tucson US");
+        assert_eq!(current.datetime, "Sat 06-18 13:15");
+        assert_eq!((current.temp, current.temp_min, current.temp_max), (91.6, 77.5, 96.1));
+        assert_eq!(current.conditions[0].description, "slight rain");
+        assert_eq!((current.rain_one_hour, current.snow_one_hour), (Some(0.2), None));
+        assert_eq!(current.pressure, 1011);
+    }
+
+    #[test]
+    fn maps_hourly_arrays_into_sections() {
+        let forecast = forecast(Granularity::Hourly);
+        let sections: Vec<&WeatherSection> = forecast.sections().map(|(_, s)| s).collect();
+        let days: Vec<String> = forecast.days().map(|(day, _)| day.to_string()).collect();
+
+        assert_eq!(sections.len(), 48);
+        assert_eq!(days, ["2022-06-18", "2022-06-19", "2022-06-20"]);
+        assert_eq!((sections[0].local.hour(), sections[0].temp, sections[0].humidity), (17, 92.7, 30));
+        assert_eq!((sections[2].conditions[0].id, sections[2].precip_type), (500, Precipitation::Rain));
+        assert_eq!((sections[2].rain_volume, sections[2].precipitation), (Some(0.4), 0.4));
+        assert_eq!((sections[1].rain_volume, sections[1].precip_type), (None, Precipitation::None));
+        assert!(!sections[5].daytime);
+    }
+
+    #[test]
+    fn maps_daily_arrays_into_sections() {
+        let forecast = forecast(Granularity::Daily);
+        let days: Vec<(String, f32, f32, f32)> = forecast.sections()
+            .map(|(day, s)| (day.to_string(), s.temp_min, s.temp_max, s.precipitation))
+            .collect();
+
+        assert_eq!(days, [
+            (String::from("2022-06-18"), 77.5, 96.1, 0.8),
+            (String::from("2022-06-19"), 76.8, 94.2, 0.0),
+            (String::from("2022-06-20"), 78.1, 95.0, 0.0),
+        ]);
+    }
+
+    #[test]
+    fn rolls_three_hours_of_precipitation_into_each_3h_section() {
+        let forecast = forecast(Granularity::ThreeHour);
+        let sections: Vec<&WeatherSection> = forecast.sections().map(|(_, s)| s).collect();
+
+        assert_eq!(sections.len(), 16);
+        assert_eq!(sections.iter().map(|s| s.local.hour()).take(3).collect::<Vec<_>>(), [17, 20, 23]);
+        // 20:00 totals the hours since 17:00, 0.0 + 0.4 + 0.4
+        assert!((sections[1].precipitation - 0.8).abs() < 1e-6);
+        assert!((sections[1].rain_volume.unwrap() - 0.8).abs() < 1e-6);
+        assert_eq!(sections[1].precip_type, Precipitation::Rain);
+        assert_eq!((sections[1].temp_min, sections[1].temp_max), (86.6, 91.1));
+        assert_eq!(sections[2].precipitation, 0.0);
+        assert_eq!(forecast.sections().map(|(_, s)| s.precipitation).sum::<f32>(), 0.8);
+    }
+
+    #[test]
+    fn converts_snowfall_from_centimetres() {
+        let mut json: serde_json::Value = serde_json::from_str(JSON_OPEN_METEO_RESPONSE).unwrap();
+        json["hourly"]["snowfall"][0] = serde_json::json!(0.7);
+        json["daily"]["snowfall_sum"][0] = serde_json::json!(1.4);
+        let data = ForecastData::parse(&json.to_string()).unwrap();
+
+        let hourly = data.transform(&place(), &query(Granularity::Hourly)).unwrap();
+        let daily = data.transform(&place(), &query(Granularity::Daily)).unwrap();
+
+        assert_eq!(hourly.sections().next().unwrap().1.snow_volume, Some(7.0));
+        assert_eq!(daily.sections().next().unwrap().1.snow_volume, Some(14.0));
+    }
+
+    #[test]
+    fn takes_day_and_night_from_is_day() {
+        let forecast = forecast(Granularity::Hourly);
+        let sections: Vec<&WeatherSection> = forecast.sections().map(|(_, s)| s).collect();
+
+        // sunset is 19:32 and sunrise 05:17, so 19:00 is day and 05:00 night
+        assert_eq!((sections[2].local.hour(), sections[2].daytime), (19, true));
+        assert_eq!((sections[12].local.hour(), sections[12].daytime), (5, false));
+        assert_eq!(sections[13].conditions[0].icon, "01d");
+    }
+
+    #[test]
+    fn rejects_short_arrays() {
+        let mut json: serde_json::Value = serde_json::from_str(JSON_OPEN_METEO_RESPONSE).unwrap();
+        json["hourly"]["temperature_2m"].as_array_mut().unwrap().pop();
+        json["daily"]["weathercode"].as_array_mut().unwrap().truncate(1);
+        let data = ForecastData::parse(&json.to_string()).unwrap();
+
+        for granularity in [Granularity::Hourly, Granularity::ThreeHour, Granularity::Daily] {
+            assert!(matches!(data.transform(&place(), &query(granularity)), Err(ApiError::Parse("open-meteo", _))));
+        }
+    }
+}
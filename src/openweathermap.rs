@@ -0,0 +1,157 @@
+use crate::backend::{datetime, AirPollutionList, CurrentWeather, Granularity, WeatherList};
+use crate::cache::{CacheMode, ResponseCache};
+use crate::display::{CurrentConditions, WeatherForecast};
+use crate::geocode::Geocoder;
+use crate::http::HttpClient;
+use crate::onecall::OneCall;
+use crate::provider::{Query, WeatherProvider};
+use crate::request::{ApiError, Endpoint, Location, WeatherApi, BASE_API_URL};
+
+// OpenWeatherMap provider, combines the weather, forecast,
+// One Call and air pollution endpoints into one forecast
+
+#[derive(Debug)]
+pub struct OpenWeatherMap {
+    api_key: String,
+    base_url: String,
+    geocoder: Geocoder,
+    cache: ResponseCache,
+    http: HttpClient,
+}
+
+impl OpenWeatherMap {
+    pub fn new(api_key: String) -> OpenWeatherMap {
+        OpenWeatherMap {
+            geocoder: Geocoder::new(api_key.clone()),
+            api_key,
+            base_url: BASE_API_URL.to_owned(),
+            cache: ResponseCache::new(),
+            http: HttpClient::new(),
+        }
+    }
+
+    // Point at a different host e.g. a local stand-in server
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+
+    pub fn with_geocoder(mut self, geocoder: Geocoder) -> Self {
+        self.geocoder = geocoder;
+        self
+    }
+
+    // Response cache, whose offline mode the geocoder follows
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.geocoder = self.geocoder.with_offline(cache.mode() == CacheMode::Offline);
+        self.cache = cache;
+        self
+    }
+
+    // Http client shared with the geocoder
+    pub fn with_http(mut self, http: HttpClient) -> Self {
+        self.geocoder = self.geocoder.with_http(http.clone());
+        self.http = http;
+        self
+    }
+
+    // Resolve place names, then fetch current weather which also
+    // yields the coordinates the air pollution and One Call endpoints need.
+    // Coordinates are named after the nearest place the geocoder knows,
+    // where OpenWeatherMap would name the closest station. Naming is
+    // cosmetic, so a failed lookup keeps the station's name
+    fn weather(&self, query: &Query) -> Result<(CurrentWeather, WeatherApi), ApiError> {
+        let (location, place) = match query.location {
+            Location::Coordinates(lat, lon) => (query.location.clone(), self.geocoder.name(lat, lon).unwrap_or_default()),
+            ref location => (self.geocoder.resolve(location, query.pick)?, None),
+        };
+
+        let api = WeatherApi::new(self.api_key.clone(), location, query.units)
+            .with_base_url(&self.base_url)
+            .with_cache(self.cache.clone())
+            .with_http(self.http.clone())
+            .with_endpoint(Endpoint::Weather);
+
+        let mut weather = api.fetch(CurrentWeather::parse)?;
+
+        if let Some(place) = place {
+            weather = weather.with_place(&place.label(), place.country());
+        }
+
+        let api = api.with_location(weather.location());
+
+        Ok((weather, api))
+    }
+
+    // Current conditions plus latest air quality when asked for
+    fn conditions(&self, query: &Query, weather: &CurrentWeather, mut api: WeatherApi) -> Result<(CurrentConditions, WeatherApi), ApiError> {
+        let mut current = weather.transform(query.clock)?;
+
+        if query.air {
+            api = api.with_endpoint(Endpoint::AirPollution);
+            let air = api.fetch(AirPollutionList::parse)?;
+            current = current.with_air_quality(air.latest());
+        }
+
+        if let Some(fetched) = api.stale_since() {
+            current = current.with_stale_since(datetime(fetched, weather.city().timezone(), &query.clock));
+        }
+
+        Ok((current, api))
+    }
+}
+
+impl WeatherProvider for OpenWeatherMap {
+    fn name(&self) -> &'static str {
+        "openweathermap"
+    }
+
+    fn current(&self, query: &Query) -> Result<CurrentConditions, ApiError> {
+        let (weather, api) = self.weather(query)?;
+
+        self.conditions(query, &weather, api).map(|(current, _)| current)
+    }
+
+    fn forecast(&self, query: &Query) -> Result<WeatherForecast, ApiError> {
+        let (weather, api) = self.weather(query)?;
+        let (current, mut api) = self.conditions(query, &weather, api)?;
+        let clock = query.clock;
+
+        let forecast: WeatherForecast = if query.granularity == Granularity::ThreeHour {
+            api = api.with_endpoint(Endpoint::Forecast);
+            let data: WeatherList = api.fetch(WeatherList::parse)?;
+            let city = weather.city();
+            // named like the current weather, as One Call forecasts are
+            let forecast = data.transform(clock, &query.filter)?.with_place(city.name(), city.country());
+
+            // alerts are only carried by One Call responses, which keys without
+            // a One Call subscription (or an offline cache without one) lack.
+            // They are extra, so failing to fetch them leaves the forecast be
+            if query.alerts {
+                api = api.with_endpoint(Endpoint::OneCall);
+
+                match api.fetch(OneCall::parse) {
+                    Ok(data) => forecast.with_alerts(data.alerts(clock)),
+                    Err(_) => forecast,
+                }
+            } else {
+                forecast
+            }
+        } else {
+            api = api.with_endpoint(Endpoint::OneCall);
+            let data: OneCall = api.fetch(OneCall::parse)?;
+            let forecast = data.transform(query.granularity, weather.city(), clock, &query.filter)?;
+
+            if query.alerts { forecast } else { forecast.with_alerts(Vec::new()) }
+        };
+
+        let mut forecast = forecast.with_current(current);
+
+        if query.air {
+            api = api.with_endpoint(Endpoint::AirPollutionForecast);
+            forecast = forecast.with_air_quality(&api.fetch(AirPollutionList::parse)?.transform());
+        }
+
+        Ok(forecast.with_stale_since(api.stale_since()))
+    }
+}
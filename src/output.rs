@@ -0,0 +1,253 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::SecondsFormat;
+use serde::Serialize;
+
+use crate::backend::{Granularity, Precipitation};
+use crate::display::{Html, Markdown, Render, WeatherForecast, WeatherSection};
+use crate::ics::Ics;
+use crate::request::ApiError;
+
+// Module renders a forecast in the format chosen with --output.
+// Tabular is the colored terminal table, json is the normalized
+// forecast for scripts and dashboards, its layout is versioned so
+// consumers can detect changes (see the README for the fields).
+// Csv and tsv flatten it into one row per section for spreadsheets,
+// markdown and html are reports for wiki pages and email, ics is
+// a calendar feed of daily events and alerts.
+
+// Bumped on any change to the json layout that isn't purely additive
+pub const JSON_VERSION: u32 = 1;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Output {
+    #[default]
+    Tabular,
+    Json,
+    Csv,
+    Tsv,
+    Markdown,
+    Html,
+    Ics,
+}
+
+impl FromStr for Output {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tabular" => Ok(Self::Tabular),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            "ics" => Ok(Self::Ics),
+            _ => Err(ApiError::BadParse("unknown output type"))
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Document<'a> {
+    version: u32,
+    #[serde(flatten)]
+    forecast: &'a WeatherForecast,
+}
+
+impl Output {
+    // Ics events summarize whole days, which the minutely
+    // sections' precipitation alone can't do
+    pub fn supports(&self, granularity: Granularity) -> Result<(), ApiError> {
+        match (self, granularity) {
+            (Self::Ics, Granularity::Minutely) => Err(ApiError::Unsupported("ics output", "minutely forecasts")),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn render(&self, forecast: &WeatherForecast) -> Result<String, ApiError> {
+        self.supports(forecast.granularity())?;
+
+        match self {
+            Self::Tabular => Ok(forecast.to_string()),
+            Self::Json => Ok(serde_json::to_string_pretty(&Document { version: JSON_VERSION, forecast })?),
+            Self::Csv => Ok(delimited(forecast, ',')),
+            Self::Tsv => Ok(delimited(forecast, '\t')),
+            Self::Markdown => Ok(Markdown.render(forecast)),
+            Self::Html => Ok(Html.render(forecast)),
+            Self::Ics => Ok(Ics.render(forecast)),
+        }
+    }
+}
+
+// Columns of the csv and tsv rows, the time is on the --tz clock
+const COLUMNS: [&str; 37] = [
+    "date", "timestamp", "time", "city", "country",
+    "temp", "feels_like", "temp_min", "temp_max", "pressure", "sea_level", "grnd_level",
+    "humidity", "clouds", "visibility", "pop", "wind_speed", "wind_deg", "wind_gust",
+    "rain_volume", "snow_volume", "precipitation", "precip_type", "daytime",
+    "aqi", "pm2_5", "pm10", "o3", "no2", "so2", "co",
+    "members", "temp_lo", "temp_hi", "precip_lo", "precip_hi", "conditions",
+];
+
+// Header then one row per section, missing values are left empty
+fn delimited(forecast: &WeatherForecast, delimiter: char) -> String {
+    let city = forecast.location();
+    let mut rows = vec![COLUMNS.join(&delimiter.to_string())];
+
+    for (day, s) in forecast.sections() {
+        let air = s.air_quality.as_ref();
+        let spread = s.spread.as_ref();
+
+        let row: Vec<String> = vec![
+            day.to_string(),
+            s.timestamp.to_string(),
+            s.local.to_rfc3339_opts(SecondsFormat::Secs, true),
+            city.name().to_owned(),
+            city.country().to_owned(),
+            s.temp.to_string(),
+            s.feels_like.to_string(),
+            s.temp_min.to_string(),
+            s.temp_max.to_string(),
+            s.pressure.to_string(),
+            optional(s.sea_level),
+            optional(s.grnd_level),
+            s.humidity.to_string(),
+            s.clouds.to_string(),
+            optional(s.visibility),
+            s.pop.to_string(),
+            s.wind_speed.to_string(),
+            s.wind_deg.to_string(),
+            optional(s.wind_gust),
+            optional(s.rain_volume),
+            optional(s.snow_volume),
+            s.precipitation.to_string(),
+            precip_type(s),
+            s.daytime.to_string(),
+            optional(air.map(|a| a.aqi)),
+            optional(air.map(|a| a.pm2_5)),
+            optional(air.map(|a| a.pm10)),
+            optional(air.map(|a| a.o3)),
+            optional(air.map(|a| a.no2)),
+            optional(air.map(|a| a.so2)),
+            optional(air.map(|a| a.co)),
+            optional(spread.map(|sp| sp.members)),
+            optional(spread.map(|sp| sp.temp.0)),
+            optional(spread.map(|sp| sp.temp.1)),
+            optional(spread.map(|sp| sp.precipitation.0)),
+            optional(spread.map(|sp| sp.precipitation.1)),
+            s.joined("; ", |c| c.description.clone()),
+        ];
+
+        let fields: Vec<Cow<'_, str>> = row.iter().map(|f| quote(f, delimiter)).collect();
+        rows.push(fields.join(&delimiter.to_string()));
+    }
+
+    rows.join("\n")
+}
+
+// Matches the json names, none rather than the table's dash
+fn precip_type(section: &WeatherSection) -> String {
+    match section.precip_type {
+        Precipitation::None => String::from("none"),
+        p => p.to_string(),
+    }
+}
+
+#[inline]
+fn optional<T: Display>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+// Fields holding the delimiter, quotes or line breaks are quoted
+// with any quotes doubled, as spreadsheets and pandas expect
+fn quote(field: &str, delimiter: char) -> Cow<'_, str> {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{group_days, City, Clock, Condition, WeatherList};
+    use crate::filter::{HourFilter, Preset};
+    use crate::mock_json::JSON_RESPONSE;
+
+    // Two sections for a city and description needing quotes in csv and tsv
+    fn awkward() -> WeatherForecast {
+        let city = City::new(String::from("Tucson, \"Old Pueblo\""), String::from("US"), 32.2217, -110.9265, 1655554625, 1655605961, -25200);
+        let sections = [1655586000, 1655596800].iter().map(|ts| {
+            let (day, mut section) = WeatherSection::at(&Clock::City, *ts, -25200);
+            section.conditions = vec![Condition {
+                id: 500,
+                group: String::from("Rain"),
+                description: String::from("light rain,\tthen \"mist\""),
+                icon: String::from("10d"),
+            }];
+            (day, section.hour, section)
+        }).collect();
+
+        WeatherForecast::new(city, Clock::City, group_days(sections, None, (6, 18)))
+    }
+
+    // Splits a row on the delimiter outside quotes, undoing the quoting
+    fn fields(row: &str, delimiter: char) -> Vec<String> {
+        let (mut fields, mut field, mut quoted) = (Vec::new(), String::new(), false);
+        let mut chars = row.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => { field.push('"'); chars.next(); },
+                '"' => quoted = !quoted,
+                c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+                c => field.push(c),
+            }
+        }
+
+        fields.push(field);
+        fields
+    }
+
+    #[test]
+    fn quotes_fields_holding_the_delimiter_or_quotes() {
+        assert_eq!(quote("Tucson", ','), "Tucson");
+        assert_eq!(quote("Tucson, AZ", ','), "\"Tucson, AZ\"");
+        assert_eq!(quote("Tucson, AZ", '\t'), "Tucson, AZ");
+        assert_eq!(quote("light\train", '\t'), "\"light\train\"");
+        assert_eq!(quote("\"Old Pueblo\"", '\t'), "\"\"\"Old Pueblo\"\"\"");
+    }
+
+    #[test]
+    fn csv_and_tsv_round_trip_awkward_text() {
+        for (output, delimiter) in [(Output::Csv, ','), (Output::Tsv, '\t')] {
+            let rendered = output.render(&awkward()).unwrap();
+            let rows: Vec<Vec<String>> = rendered.lines().map(|r| fields(r, delimiter)).collect();
+
+            assert_eq!(rows.len(), 3);
+            assert_eq!(rows[0], COLUMNS);
+            for row in &rows[1..] {
+                assert_eq!(row[3], "Tucson, \"Old Pueblo\"");
+                assert_eq!(row.last().unwrap(), "light rain,\tthen \"mist\"");
+            }
+        }
+    }
+
+    #[test]
+    fn every_row_has_a_field_per_column() {
+        let forecast = WeatherList::parse(JSON_RESPONSE).unwrap()
+            .transform(Clock::City, &HourFilter::from(Preset::All))
+            .unwrap();
+
+        for (output, delimiter) in [(Output::Csv, ','), (Output::Tsv, '\t')] {
+            let rendered = output.render(&forecast).unwrap();
+
+            assert_eq!(rendered.lines().count(), 41);
+            assert!(rendered.lines().all(|row| fields(row, delimiter).len() == COLUMNS.len()));
+        }
+    }
+}
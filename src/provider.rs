@@ -0,0 +1,151 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::backend::{Clock, Granularity};
+use crate::cache::{CacheMode, ResponseCache};
+use crate::display::{CurrentConditions, WeatherForecast};
+use crate::filter::HourFilter;
+use crate::http::HttpClient;
+use crate::metno::MetNo;
+use crate::nws::Nws;
+use crate::openmeteo::OpenMeteo;
+use crate::openweathermap::OpenWeatherMap;
+use crate::request::{ApiError, Location, Units};
+
+// Module defines the seam between web services and the rest of weathr.
+// Each provider fetches from its own api and normalizes into the shared
+// WeatherForecast / WeatherSection model, so display never changes
+// when a backend is added.
+
+pub trait WeatherProvider {
+    // Short identifier shown to users
+    fn name(&self) -> &'static str;
+
+    // Conditions right now
+    fn current(&self, query: &Query) -> Result<CurrentConditions, ApiError>;
+
+    // Normalized forecast, with current conditions and alerts where the service has them
+    fn forecast(&self, query: &Query) -> Result<WeatherForecast, ApiError>;
+}
+
+
+// User supplied parameters every provider receives
+
+#[derive(Clone, Debug)]
+pub struct Query {
+    pub location: Location,
+    pub units: Units,
+    pub clock: Clock,
+    pub filter: HourFilter,
+    pub granularity: Granularity,
+    pub air: bool,
+    pub alerts: bool,
+    pub pick: Option<usize>,
+}
+
+impl Query {
+    pub fn new(location: Location, units: Units) -> Query {
+        Query {
+            location,
+            units,
+            clock: Clock::default(),
+            filter: HourFilter::default(),
+            granularity: Granularity::default(),
+            air: false,
+            alerts: true,
+            pick: None,
+        }
+    }
+}
+
+
+// Selectable web services
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Provider {
+    #[default]
+    OpenWeatherMap,
+    OpenMeteo,
+    Nws,
+    MetNo,
+}
+
+impl FromStr for Provider {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "openweathermap" | "owm" => Ok(Self::OpenWeatherMap),
+            "open-meteo" | "openmeteo" => Ok(Self::OpenMeteo),
+            "nws" => Ok(Self::Nws),
+            "met.no" | "metno" => Ok(Self::MetNo),
+            _ => Err(ApiError::BadParse("unknown provider"))
+        }
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OpenWeatherMap => write!(f, "openweathermap"),
+            Self::OpenMeteo => write!(f, "open-meteo"),
+            Self::Nws => write!(f, "nws"),
+            Self::MetNo => write!(f, "met.no"),
+        }
+    }
+}
+
+impl Provider {
+    // OpenWeatherMap when an api key is available, otherwise the keyless Open-Meteo
+    pub fn default_for(api_key: Option<&str>) -> Provider {
+        match api_key {
+            Some(_) => Self::OpenWeatherMap,
+            None => Self::OpenMeteo,
+        }
+    }
+
+    // Parse a comma separated list of providers e.g. "open-meteo,met.no"
+    pub fn list(providers: &str) -> Result<Vec<Provider>, ApiError> {
+        providers.split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(Provider::from_str)
+            .collect()
+    }
+
+    // Construct the provider, api_key is required by those services needing one.
+    // Only OpenWeatherMap responses are cached, so only it can run offline
+    pub fn build(&self, api_key: Option<&str>, cache: &ResponseCache, http: &HttpClient) -> Result<Box<dyn WeatherProvider>, ApiError> {
+        if cache.mode() == CacheMode::Offline && *self != Self::OpenWeatherMap {
+            return Err(ApiError::OfflineUnsupported(self.to_string()))
+        }
+
+        match self {
+            Self::OpenWeatherMap => {
+                let key = api_key.ok_or(ApiError::MissingApiKey("openweathermap"))?;
+                Ok(Box::new(OpenWeatherMap::new(key.to_owned()).with_cache(cache.clone()).with_http(http.clone())))
+            },
+            Self::OpenMeteo => Ok(Box::new(OpenMeteo::new().with_http(http.clone()))),
+            Self::Nws => Ok(Box::new(Nws::new().with_http(http.clone()))),
+            Self::MetNo => Ok(Box::new(MetNo::new().with_http(http.clone()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_offline_only_with_openweathermap() {
+        let cache = ResponseCache::new().with_dir(None).with_mode(CacheMode::Offline);
+        let http = HttpClient::new();
+
+        // the default without an api key is open-meteo, which is not cached
+        let keyless = Provider::default_for(None).build(None, &cache, &http);
+
+        assert!(matches!(keyless, Err(ApiError::OfflineUnsupported(ref p)) if p == "open-meteo"));
+        assert!(Provider::OpenWeatherMap.build(Some("key"), &cache, &http).is_ok());
+        assert!(Provider::MetNo.build(None, &ResponseCache::new().with_dir(None), &http).is_ok());
+    }
+}
@@ -1,53 +1,193 @@
+use std::cell::Cell;
+use std::fmt;
 use std::str::FromStr;
+use serde::{Deserialize, Serialize, Serializer};
 use url::Url;
 use ureq;
 
+use crate::cache::ResponseCache;
+use crate::http::HttpClient;
+
 // Module serves to wrap functionality around placing
 // a HTTP request.
 
-// Currently only tailored to one web service
+// Endpoints are those of OpenWeatherMap, other web services
+// are reached through their WeatherProvider implementations
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Endpoint {
-    CurrentWeather,
+    Forecast,
+    Weather,
+    AirPollution,
+    AirPollutionForecast,
+    OneCall,
 }
 
 impl Endpoint {
-    fn value(&self) -> &str {
+    // Path relative to the base url, One Call lives under the 3.0 api
+    fn value(&self) -> &'static str {
+        match self {
+            Self::Forecast => "data/2.5/forecast",
+            Self::Weather => "data/2.5/weather",
+            Self::AirPollution => "data/2.5/air_pollution",
+            Self::AirPollutionForecast => "data/2.5/air_pollution/forecast",
+            Self::OneCall => "data/3.0/onecall",
+        }
+    }
+
+    // Seconds a cached response stays current, forecasts are
+    // only recomputed every 3 hours while conditions change sooner
+    fn ttl(&self) -> u64 {
         match self {
-            Self::CurrentWeather => "forecast"
+            Self::Forecast => 3 * 60 * 60,
+            Self::Weather | Self::OneCall => 10 * 60,
+            Self::AirPollution | Self::AirPollutionForecast => 60 * 60,
         }
     }
 }
 
-#[derive(Debug)]
+// Ways of specifying a location understood by the web service
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Location {
     City(String),
-    //    CityCountry(String, String),
-    //    CityStateCountry(State, String, String),
+    CityCountry(String, String),
+    CityStateCountry(String, String, String),
+    Zip(String, Option<String>),
+    Coordinates(f64, f64),
+    Id(u64),
 }
 
 impl Default for Location {
     fn default() -> Self { Location::City(String::from("London")) }
 }
 
+// Detects the location syntax given, e.g.
+//   "id:5746545"          city id
+//   "45.5,-122.6"         lat,lon
+//   "97201" "97201,US"    zip code with optional country
+//   "Portland,OR,US"      city,state,country
+//   "Portland,US"         city,country
+//   "Portland"            city
 impl FromStr for Location {
     type Err = ApiError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::City(s.to_owned()))
+        let s = s.trim();
+
+        if let Some(id) = s.strip_prefix("id:") {
+            return id.trim().parse::<u64>()
+                .map(Self::Id)
+                .map_err(|_| ApiError::BadParse("city id must be numeric, e.g. id:5746545"));
+        }
+
+        let tokens: Vec<&str> = s.split(',').map(str::trim).collect();
+
+        if tokens.iter().any(|t| t.is_empty()) {
+            return Err(ApiError::BadParse("location has an empty component"))
+        }
+
+        if let [lat, lon] = tokens[..] {
+            if let (Ok(lat), Ok(lon)) = (lat.parse::<f64>(), lon.parse::<f64>()) {
+                if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
+                    return Ok(Self::Coordinates(lat, lon))
+                }
+
+                return Err(ApiError::BadParse("coordinates out of range"))
+            }
+        }
+
+        match tokens[..] {
+            [zip] if starts_with_digit(zip) => Ok(Self::Zip(zip.to_owned(), None)),
+            [zip, country] if starts_with_digit(zip) => Ok(Self::Zip(zip.to_owned(), Some(country.to_owned()))),
+            [city] => Ok(Self::City(city.to_owned())),
+            [city, country] => Ok(Self::CityCountry(city.to_owned(), country.to_owned())),
+            [city, state, country] => Ok(Self::CityStateCountry(city.to_owned(), state.to_owned(), country.to_owned())),
+            _ => Err(ApiError::BadParse("unrecognized location format")),
+        }
     }
 }
 
+fn starts_with_digit(s: &str) -> bool {
+    s.chars().next().is_some_and(|c| c.is_ascii_digit())
+}
 
-#[derive(Debug)]
+impl Location {
+    // Query parameters identifying this location
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::City(city) => vec![("q", city.clone())],
+            Self::CityCountry(city, country) => vec![("q", format!("{},{}", city, country))],
+            Self::CityStateCountry(city, state, country) => vec![("q", format!("{},{},{}", city, state, country))],
+            Self::Zip(zip, None) => vec![("zip", zip.clone())],
+            Self::Zip(zip, Some(country)) => vec![("zip", format!("{},{}", zip, country))],
+            Self::Coordinates(lat, lon) => vec![("lat", lat.to_string()), ("lon", lon.to_string())],
+            Self::Id(id) => vec![("id", id.to_string())],
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::City(city) => write!(f, "{}", city),
+            Self::CityCountry(city, country) => write!(f, "{},{}", city, country),
+            Self::CityStateCountry(city, state, country) => write!(f, "{},{},{}", city, state, country),
+            Self::Zip(zip, None) => write!(f, "{}", zip),
+            Self::Zip(zip, Some(country)) => write!(f, "{},{}", zip, country),
+            Self::Coordinates(lat, lon) => write!(f, "{},{}", lat, lon),
+            Self::Id(id) => write!(f, "id:{}", id),
+        }
+    }
+}
+
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum Units {
     Metric,
+    #[default]
     Imperial,
 }
 
-impl Default for Units {
-    fn default() -> Self { Units::Imperial }
+impl Units {
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Metric => "metric",
+            Self::Imperial => "imperial",
+        }
+    }
+}
+
+// Serialized as the unit of each kind of value, so consumers
+// needn't know what metric or imperial mean to each provider
+impl Serialize for Units {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Labels {
+            system: &'static str,
+            temperature: &'static str,
+            wind_speed: &'static str,
+            precipitation: &'static str,
+            pressure: &'static str,
+            humidity: &'static str,
+            visibility: &'static str,
+        }
+
+        let (temperature, wind_speed) = match self {
+            Self::Metric => ("celsius", "m/s"),
+            Self::Imperial => ("fahrenheit", "mph"),
+        };
+
+        Labels {
+            system: self.value(),
+            temperature,
+            wind_speed,
+            precipitation: "mm",
+            pressure: "hPa",
+            humidity: "%",
+            visibility: "m",
+        }.serialize(serializer)
+    }
 }
 
 impl FromStr for Units {
@@ -71,14 +211,158 @@ impl FromStr for Units {
 #[derive(Debug, thiserror::Error)]
 pub enum ApiError {
     #[error("Request error")]
-    Request(#[from] ureq::Error),
+    Request(#[from] Box<ureq::Error>),
     #[error("Unable to transform response to string")]
     ConvertResponse(#[from] std::io::Error),
     #[error("Parsing URL error")]
     ParseURL(#[from] url::ParseError),
     #[error("Unsupported parse type {0}")]
     BadParse(&'static str),
+    #[error("Unable to parse response")]
+    Json(#[from] serde_json::Error),
+    #[error("Provider {0} needs an api key, export WEATHER_API_KEY=")]
+    MissingApiKey(&'static str),
+    #[error("{0} does not support {1}")]
+    Unsupported(&'static str, &'static str),
+    #[error("--offline only works with openweathermap, not {0}")]
+    OfflineUnsupported(String),
+    #[error("Forecast entry {0} has no weather conditions")]
+    NoConditions(String),
+    #[error("No places found matching {0}")]
+    NoMatches(String),
+    #[error("Invalid selection {0}, expected 1 to {1}")]
+    BadPick(usize, usize),
+    #[error("{0} places match and none was chosen")]
+    NoSelection(usize),
+    #[error("No cached response for {0}, run once without --offline first")]
+    NotCached(String),
+    #[error("No NWS forecast for {0}")]
+    NotCovered(String),
+    #[error("City not found ({0})")]
+    CityNotFound(String),
+    #[error("Invalid api key ({0})")]
+    InvalidApiKey(String),
+    #[error("Rate limited ({0})")]
+    RateLimited(String),
+    #[error("Server error {0} ({1})")]
+    ServerError(u16, String),
+    #[error("Unexpected {0} response ({1})")]
+    Parse(&'static str, String),
+}
+
+// OpenWeatherMap error body e.g. {"cod":"404","message":"city not found"},
+// cod is a string on some endpoints and a number on others
+#[derive(Deserialize, Debug)]
+struct ErrorBody {
+    cod: Cod,
+    message: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Cod {
+    Number(u16),
+    Text(String),
+}
+
+impl Cod {
+    fn code(&self) -> Option<u16> {
+        match self {
+            Cod::Number(n) => Some(*n),
+            Cod::Text(s) => s.parse().ok(),
+        }
+    }
+}
+
+impl ApiError {
+    // Service outages worth trying another provider for: no connection,
+    // a rejected key, rate limiting or a server side failure
+    pub fn is_outage(&self) -> bool {
+        match self {
+            ApiError::Request(e) => match e.as_ref() {
+                ureq::Error::Status(code, _) => matches!(code, 401 | 429 | 500..=599),
+                ureq::Error::Transport(_) => true,
+            },
+            ApiError::ConvertResponse(_) => true,
+            ApiError::InvalidApiKey(_) | ApiError::RateLimited(_) | ApiError::ServerError(..) => true,
+            _ => false,
+        }
+    }
+
+    // What the user can do about it, where there is something
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            ApiError::OfflineUnsupported(_) => Some("Only OpenWeatherMap responses are cached, pass --provider openweathermap with WEATHER_API_KEY set"),
+            ApiError::NoSelection(_) => Some("Pass --pick N to choose one without prompting e.g. when run from cron"),
+            ApiError::NotCovered(_) => Some("NWS only covers US locations, try --provider open-meteo"),
+            ApiError::CityNotFound(_) => Some("Check the spelling, add a country code e.g. paris,fr or give lat,lon instead"),
+            ApiError::InvalidApiKey(_) => Some("Check WEATHER_API_KEY, new keys can take a couple of hours to activate"),
+            ApiError::RateLimited(_) => Some("Too many calls for the subscription, wait a minute or use --offline"),
+            ApiError::ServerError(..) => Some("The weather service is having trouble, try again later or another --provider"),
+            ApiError::Parse(..) => Some("The service sent something other than weather data, try again with --refresh"),
+            _ => None,
+        }
+    }
+
+    // Process exit status, 3 is taken by active weather alerts
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ApiError::CityNotFound(_) => 4,
+            ApiError::InvalidApiKey(_) => 5,
+            ApiError::RateLimited(_) => 6,
+            ApiError::ServerError(..) => 7,
+            ApiError::Parse(..) => 8,
+            _ => 1,
+        }
+    }
+
+    // Typed error for an OpenWeatherMap failure status, explained by the
+    // message in its body. Other errors pass through unchanged
+    pub fn decode(self) -> ApiError {
+        match self {
+            ApiError::Request(e) => match *e {
+                ureq::Error::Status(code, response) if ApiError::is_typed(code) => {
+                    let body = response.into_string().unwrap_or_default();
+                    let message = serde_json::from_str::<ErrorBody>(&body).ok().and_then(|b| b.message);
+
+                    ApiError::status(code, message.unwrap_or_else(|| format!("status {}", code)))
+                },
+                e => ApiError::Request(Box::new(e)),
+            },
+            e => e,
+        }
+    }
+
+    // Typed error from an error body served with a success status
+    fn from_body(body: &str) -> Option<ApiError> {
+        let error: ErrorBody = serde_json::from_str(body).ok()?;
+        let code = error.cod.code().filter(|c| ApiError::is_typed(*c))?;
+
+        Some(ApiError::status(code, error.message.unwrap_or_else(|| format!("cod {}", code))))
+    }
+
+    // Failure statuses with a typed error of their own
+    #[inline]
+    fn is_typed(code: u16) -> bool {
+        matches!(code, 401 | 404 | 429 | 500..=599)
+    }
 
+    // Typed error for a status passing is_typed
+    fn status(code: u16, message: String) -> ApiError {
+        match code {
+            401 => ApiError::InvalidApiKey(message),
+            404 => ApiError::CityNotFound(message),
+            429 => ApiError::RateLimited(message),
+            _ => ApiError::ServerError(code, message),
+        }
+    }
+}
+
+// ureq::Error is large, box it to keep ApiError small
+impl From<ureq::Error> for ApiError {
+    fn from(e: ureq::Error) -> Self {
+        ApiError::Request(Box::new(e))
+    }
 }
 
 
@@ -88,40 +372,85 @@ pub enum ApiError {
 #[derive(Debug)]
 pub struct WeatherApi {
     api_key: String,
+    base_url: String,
     endpoint: Endpoint,
     location: Location,
     units: Units,
+    cache: ResponseCache,
+    http: HttpClient,
+    // oldest cached response served while offline
+    stale_since: Cell<Option<u64>>,
 //    debug: bool,
 }
 
-const BASE_API_URL: &str = "https://api.openweathermap.org/data/2.5";
+pub const BASE_API_URL: &str = "https://api.openweathermap.org";
+
+// Identifies weathr to services whose terms require it (NWS, MET Norway)
+pub const USER_AGENT: &str = concat!("weathr/", env!("CARGO_PKG_VERSION"), " (https://github.com/brpandey/weathr)");
 
 impl WeatherApi {
     pub fn new(api_key: String, location: Location, units: Units) -> WeatherApi {
         WeatherApi {
             api_key,
-            endpoint: Endpoint::CurrentWeather,
+            base_url: BASE_API_URL.to_owned(),
+            endpoint: Endpoint::Forecast,
             location,
             units,
+            cache: ResponseCache::new().with_dir(None),
+            http: HttpClient::new(),
+            stale_since: Cell::new(None),
 //            debug: false,
         }
     }
 
+    // Point at a different host e.g. a local stand-in server
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn with_http(mut self, http: HttpClient) -> Self {
+        self.http = http;
+        self
+    }
+
+    // When the oldest response served from an offline cache was fetched
+    #[inline]
+    pub fn stale_since(&self) -> Option<u64> {
+        self.stale_since.get()
+    }
+
     pub fn api_key(&self) -> &str {
         self.api_key.as_ref()
     }
 
-    pub fn location(&self) -> &str {
-        match self.location {
-            Location::City(ref name) => name.as_ref()
-        }
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    pub fn endpoint(&self) -> Endpoint {
+        self.endpoint
+    }
+
+    // Switch which endpoint subsequent requests are placed against
+    pub fn with_endpoint(mut self, endpoint: Endpoint) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    // Replace location e.g. once geocoded into coordinates
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = location;
+        self
     }
 
     pub fn units(&self) -> &str {
-        match self.units {
-            Units::Metric => "metric",
-            Units::Imperial => "imperial",
-        }
+        self.units.value()
     }
 
     // Generate WeatherApi given api_key and user supplied cli args
@@ -139,24 +468,107 @@ impl WeatherApi {
 
     // Generate final url given api parameters
     fn url_construct(&self) -> Result<String, ApiError> {
-        let mut url = Url::parse(BASE_API_URL)?;
+        let mut url = Url::parse(&self.base_url)?;
         let endpoint = self.endpoint.value();
 
-        url.path_segments_mut().unwrap().push(endpoint);
+        url.path_segments_mut()
+            .map_err(|_| ApiError::BadParse("base url cannot be a base"))?
+            .pop_if_empty()
+            .extend(endpoint.split('/'));
 
         url.query_pairs_mut()
-            .append_pair("q", self.location())
+            .extend_pairs(self.location.query())
             .append_pair("appid", self.api_key())
             .append_pair("units", self.units());
 
         Ok(url.to_string())
     }
 
-    // Places HTTP get (blocking) call using ureq
+    // Places HTTP get (blocking) call, retried on transient failures
     pub fn request(&self) -> Result<String, ApiError> {
         let url = self.url_construct()?;
-        let response = ureq::get(&url).call()?.into_string()?;
 
-        Ok(response)
+        Ok(self.http.call(self.http.get(&url)).map_err(ApiError::decode)?.into_string()?)
+    }
+
+    // Parsed response, from the cache when it holds a current one for the
+    // same query. Bodies that are not what the endpoint returns become
+    // typed errors and are never cached
+    pub fn fetch<T>(&self, parse: fn(&str) -> Result<T, serde_json::Error>) -> Result<T, ApiError> {
+        let key = format!("{}_{}_{}", self.endpoint.value(), self.location, self.units());
+
+        let (value, fetched) = self.cache.fetch(&key, self.endpoint.ttl(), || self.request(), |response| {
+            parse(response).map_err(|e| ApiError::from_body(response)
+                .unwrap_or_else(|| ApiError::Parse(self.endpoint.value(), e.to_string())))
+        })?;
+
+        if let Some(fetched) = fetched {
+            self.stale_since.set(Some(self.stale_since.get().map_or(fetched, |s| s.min(fetched))));
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Failure status as ureq reports it, with the body served
+    fn status(code: u16, body: &str) -> ApiError {
+        let response = ureq::Response::new(code, "Error", body).unwrap();
+
+        ApiError::from(ureq::Error::Status(code, response))
+    }
+
+    #[test]
+    fn decodes_error_bodies_with_cod_as_string_or_number() {
+        let text = ApiError::from_body(r#"{"cod":"404","message":"city not found"}"#);
+        let number = ApiError::from_body(r#"{"cod":401,"message":"Invalid API key."}"#);
+
+        assert!(matches!(text, Some(ApiError::CityNotFound(ref m)) if m == "city not found"));
+        assert!(matches!(number, Some(ApiError::InvalidApiKey(ref m)) if m == "Invalid API key."));
+        assert!(matches!(ApiError::from_body(r#"{"cod":"429"}"#), Some(ApiError::RateLimited(ref m)) if m == "cod 429"));
+    }
+
+    #[test]
+    fn leaves_other_bodies_alone() {
+        // a forecast carries "cod":"200", and some bodies are not errors at all
+        assert!(ApiError::from_body(r#"{"cod":"200","message":0,"cnt":40}"#).is_none());
+        assert!(ApiError::from_body(r#"{"cod":"abc"}"#).is_none());
+        assert!(ApiError::from_body(r#"{"coord":{"lon":-110.9,"lat":32.2}}"#).is_none());
+        assert!(ApiError::from_body("<html>bad gateway</html>").is_none());
+    }
+
+    #[test]
+    fn decodes_failure_statuses() {
+        let not_found = status(404, r#"{"cod":"404","message":"city not found"}"#).decode();
+        let key = status(401, r#"{"cod":401,"message":"Invalid API key."}"#).decode();
+        let limited = status(429, "").decode();
+        let gateway = status(502, "<html>bad gateway</html>").decode();
+
+        assert!(matches!(not_found, ApiError::CityNotFound(ref m) if m == "city not found"));
+        assert!(matches!(key, ApiError::InvalidApiKey(ref m) if m == "Invalid API key."));
+        assert!(matches!(limited, ApiError::RateLimited(ref m) if m == "status 429"));
+        assert!(matches!(gateway, ApiError::ServerError(502, ref m) if m == "status 502"));
+
+        // statuses without a typed error pass through
+        assert!(matches!(status(400, "{}").decode(), ApiError::Request(_)));
+        assert!(matches!(ApiError::BadParse("x").decode(), ApiError::BadParse("x")));
+    }
+
+    #[test]
+    fn exit_codes_follow_the_error() {
+        let codes: Vec<i32> = [
+            ApiError::CityNotFound(String::new()),
+            ApiError::InvalidApiKey(String::new()),
+            ApiError::RateLimited(String::new()),
+            ApiError::ServerError(503, String::new()),
+            ApiError::Parse("forecast", String::new()),
+            ApiError::NoMatches(String::new()),
+        ].iter().map(ApiError::exit_code).collect();
+
+        assert_eq!(codes, [4, 5, 6, 7, 8, 1]);
+        assert_eq!(status(404, r#"{"cod":404}"#).decode().exit_code(), 4);
     }
 }
@@ -0,0 +1,77 @@
+// Helpers shared by the integration tests, each test crate uses only some
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Stand-in server answering each connection with the next scripted
+// response, keeping the head of every request it receives.
+// A None entry accepts the request but never answers it
+pub struct Server {
+    pub url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl Server {
+    pub fn hits(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    // Request line and headers of each request so far
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+pub fn serve(script: Vec<Option<String>>) -> Server {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = requests.clone();
+
+    thread::spawn(move || {
+        let mut stalled = Vec::new();
+
+        for (stream, reply) in listener.incoming().zip(script) {
+            let mut stream = stream.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+
+            received.lock().unwrap().push(String::from_utf8_lossy(&request).into_owned());
+
+            match reply {
+                Some(reply) => { let _ = stream.write_all(reply.as_bytes()); },
+                None => stalled.push(stream),
+            }
+        }
+    });
+
+    Server { url, requests }
+}
+
+// Complete response with the given status line, extra headers and body
+pub fn respond(status: &str, headers: &[&str], body: &str) -> Option<String> {
+    let headers: String = headers.iter().map(|h| format!("{}\r\n", h)).collect();
+
+    Some(format!("HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}", status, headers, body.len(), body))
+}
+
+// Empty scratch directory unique to the calling test
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("weathr-test-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
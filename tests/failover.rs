@@ -0,0 +1,65 @@
+mod common;
+
+use weathr::backend::Clock;
+use weathr::failover::Failover;
+use weathr::http::HttpClient;
+use weathr::mock_json::JSON_OPEN_METEO_RESPONSE;
+use weathr::nws::Nws;
+use weathr::openmeteo::OpenMeteo;
+use weathr::provider::{Query, WeatherProvider};
+use weathr::request::{ApiError, Location, Units};
+
+use common::{respond, serve, Server};
+
+fn failing(status: &str) -> Option<String> {
+    respond(status, &["Content-Type: application/problem+json"], "{}")
+}
+
+// NWS falling back to Open-Meteo, each on its own stand-in server
+fn failover(primary: &Server, fallback: &Server) -> Failover {
+    Failover::new(vec![
+        Box::new(Nws::new()
+            .with_base_url(primary.url.trim_end_matches('/'), &format!("{}search", primary.url))
+            .with_http(HttpClient::new().with_retries(0))),
+        Box::new(OpenMeteo::new()
+            .with_base_url(&format!("{}forecast", fallback.url), &format!("{}search", fallback.url))
+            .with_http(HttpClient::new().with_retries(0))),
+    ])
+}
+
+fn tucson() -> Query {
+    Query::new(Location::Coordinates(32.2217, -110.9265), Units::Imperial)
+}
+
+#[test]
+fn falls_through_to_the_fallback_when_the_primary_is_down() {
+    let primary = serve(vec![failing("503 Service Unavailable")]);
+    let fallback = serve(vec![respond("200 OK", &["Content-Type: application/json"], JSON_OPEN_METEO_RESPONSE)]);
+
+    let forecast = failover(&primary, &fallback).forecast(&tucson()).unwrap();
+
+    assert!(forecast.location().describe(&Clock::Utc).contains("via open-meteo"));
+    assert_eq!((primary.hits(), fallback.hits()), (1, 1));
+}
+
+#[test]
+fn returns_client_errors_without_falling_through() {
+    let primary = serve(vec![failing("400 Bad Request")]);
+    let fallback = serve(vec![]);
+
+    let error = failover(&primary, &fallback).forecast(&tucson()).unwrap_err();
+
+    assert!(matches!(error, ApiError::Request(ref e) if matches!(**e, ureq::Error::Status(400, _))), "{:?}", error);
+    assert_eq!(fallback.hits(), 0);
+}
+
+#[test]
+fn returns_the_last_error_when_every_provider_is_down() {
+    let primary = serve(vec![failing("503 Service Unavailable")]);
+    let fallback = serve(vec![failing("502 Bad Gateway")]);
+
+    let error = failover(&primary, &fallback).forecast(&tucson()).unwrap_err();
+
+    assert!(matches!(error, ApiError::Request(ref e) if matches!(**e, ureq::Error::Status(502, _))), "{:?}", error);
+    assert_eq!((primary.hits(), fallback.hits()), (1, 1));
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use std::path::Path;
+
+use weathr::geocode::Geocoder;
+use weathr::http::HttpClient;
+use weathr::request::{ApiError, Location};
+
+use common::{respond, scratch_dir, serve};
+
+const PORTLAND_OR: &str = r#"{"name":"Portland","state":"Oregon","country":"US","lat":45.5202,"lon":-122.6742}"#;
+const PORTLAND_ME: &str = r#"{"name":"Portland","state":"Maine","country":"US","lat":43.6591,"lon":-70.2568}"#;
+
+fn places(places: &[&str]) -> Option<String> {
+    respond("200 OK", &["Content-Type: application/json"], &format!("[{}]", places.join(",")))
+}
+
+fn geocoder(url: &str, cache: &Path) -> Geocoder {
+    Geocoder::new(String::from("test-key"))
+        .with_base_url(url)
+        .with_cache(Some(cache.join("locations.json")))
+        .with_http(HttpClient::new().with_retries(0))
+}
+
+fn portland() -> Location {
+    "Portland".parse().unwrap()
+}
+
+#[test]
+fn resolves_a_single_match() {
+    let server = serve(vec![places(&[PORTLAND_OR])]);
+    let dir = scratch_dir("geocode-single");
+
+    let location = geocoder(&server.url, &dir).resolve(&portland(), None).unwrap();
+
+    assert_eq!(location, Location::Coordinates(45.5202, -122.6742));
+    let request = &server.requests()[0];
+    assert!(request.starts_with("GET /direct?appid=test-key&q=Portland&limit=5 "), "{}", request);
+}
+
+#[test]
+fn picks_among_several_matches() {
+    let server = serve(vec![places(&[PORTLAND_OR, PORTLAND_ME])]);
+    let dir = scratch_dir("geocode-pick");
+
+    let location = geocoder(&server.url, &dir).resolve(&portland(), Some(2)).unwrap();
+
+    assert_eq!(location, Location::Coordinates(43.6591, -70.2568));
+}
+
+#[test]
+fn rejects_an_out_of_range_pick() {
+    let server = serve(vec![places(&[PORTLAND_OR, PORTLAND_ME]), places(&[PORTLAND_OR, PORTLAND_ME])]);
+    let dir = scratch_dir("geocode-bad-pick");
+    let geocoder = geocoder(&server.url, &dir);
+
+    assert!(matches!(geocoder.resolve(&portland(), Some(3)), Err(ApiError::BadPick(3, 2))));
+    assert!(matches!(geocoder.resolve(&portland(), Some(0)), Err(ApiError::BadPick(0, 2))));
+}
+
+#[test]
+fn reports_no_matches() {
+    let server = serve(vec![places(&[])]);
+    let dir = scratch_dir("geocode-none");
+
+    match geocoder(&server.url, &dir).resolve(&portland(), None) {
+        Err(ApiError::NoMatches(name)) => assert_eq!(name, "Portland"),
+        other => panic!("expected no matches, got {:?}", other),
+    }
+}
+
+#[test]
+fn cached_place_skips_the_network() {
+    let server = serve(vec![places(&[PORTLAND_OR])]);
+    let dir = scratch_dir("geocode-cache");
+    let geocoder = geocoder(&server.url, &dir);
+
+    let first = geocoder.resolve(&portland(), None).unwrap();
+    let second = geocoder.resolve(&"portland".parse().unwrap(), None).unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(server.hits(), 1);
+}
+
+#[test]
+fn leaves_unambiguous_locations_alone() {
+    let server = serve(vec![]);
+    let dir = scratch_dir("geocode-coordinates");
+    let coordinates = Location::Coordinates(45.5, -122.6);
+
+    assert_eq!(geocoder(&server.url, &dir).resolve(&coordinates, None).unwrap(), coordinates);
+    assert_eq!(server.hits(), 0);
+}
+
+#[test]
+fn offline_resolves_from_the_cache_alone() {
+    let server = serve(vec![places(&[PORTLAND_OR, PORTLAND_ME])]);
+    let dir = scratch_dir("geocode-offline");
+
+    geocoder(&server.url, &dir).resolve(&portland(), Some(1)).unwrap();
+    let offline = geocoder(&server.url, &dir).with_offline(true);
+
+    // a pick can't re-resolve offline, the cached choice stands
+    assert_eq!(offline.resolve(&portland(), None).unwrap(), Location::Coordinates(45.5202, -122.6742));
+    assert_eq!(offline.resolve(&portland(), Some(2)).unwrap(), Location::Coordinates(45.5202, -122.6742));
+
+    match offline.resolve(&"Salem".parse().unwrap(), None) {
+        Err(ApiError::NotCached(key)) => assert_eq!(key, "salem"),
+        other => panic!("expected not cached, got {:?}", other),
+    }
+    assert_eq!(server.hits(), 1);
+}
+
+#[test]
+fn names_coordinates_with_a_reverse_lookup() {
+    let server = serve(vec![places(&[PORTLAND_ME])]);
+    let dir = scratch_dir("geocode-reverse");
+    let geocoder = geocoder(&server.url, &dir);
+
+    let place = geocoder.name(43.6591, -70.2568).unwrap().unwrap();
+    let again = geocoder.name(43.6591, -70.2568).unwrap().unwrap();
+
+    assert_eq!((place.label(), place.country()), (String::from("Portland, Maine"), "US"));
+    assert_eq!(place, again);
+    assert_eq!(server.hits(), 1);
+    let request = &server.requests()[0];
+    assert!(request.starts_with("GET /reverse?appid=test-key&lat=43.6591&lon=-70.2568&limit=1 "), "{}", request);
+}
+
+#[test]
+fn leaves_unnamed_coordinates_unnamed() {
+    let server = serve(vec![places(&[])]);
+    let dir = scratch_dir("geocode-unnamed");
+
+    assert_eq!(geocoder(&server.url, &dir).name(0.0, -140.0).unwrap(), None);
+    assert_eq!(geocoder(&server.url, &dir).with_offline(true).name(45.5, -122.6).unwrap(), None);
+    assert_eq!(server.hits(), 1);
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use std::path::Path;
+
+use weathr::http::HttpClient;
+use weathr::metno::MetNo;
+use weathr::mock_json::JSON_MET_NO_RESPONSE;
+use weathr::provider::{Query, WeatherProvider};
+use weathr::request::{Location, Units};
+
+use common::{respond, scratch_dir, serve, Server};
+
+const PHOENIX: &str = r#"{"latitude":32.22,"longitude":-110.93,"utc_offset_seconds":-25200,"timezone":"America/Phoenix","timezone_abbreviation":"MST"}"#;
+const LAST_MODIFIED: &str = "Last-Modified: Sat, 18 Jun 2022 18:00:00 GMT";
+
+fn timezone() -> Option<String> {
+    respond("200 OK", &["Content-Type: application/json"], PHOENIX)
+}
+
+fn compact(expires: &str) -> Option<String> {
+    respond("200 OK", &["Content-Type: application/json", LAST_MODIFIED, &format!("Expires: {}", expires)], JSON_MET_NO_RESPONSE)
+}
+
+fn metno(server: &Server, cache: &Path) -> MetNo {
+    MetNo::new()
+        .with_base_url(&format!("{}compact", server.url), &format!("{}search", server.url))
+        .with_timezone_url(&format!("{}forecast", server.url))
+        .with_cache(Some(cache.to_path_buf()))
+        .with_http(HttpClient::new().with_retries(0))
+}
+
+fn tucson() -> Query {
+    Query::new(Location::Coordinates(32.2217, -110.9265), Units::Metric)
+}
+
+#[test]
+fn looks_up_the_timezone_of_coordinates() {
+    let server = serve(vec![timezone(), compact("Fri, 01 Jan 2100 00:00:00 GMT")]);
+    let dir = scratch_dir("metno-timezone");
+
+    let forecast = metno(&server, &dir).forecast(&tucson()).unwrap();
+
+    assert_eq!(forecast.location().timezone(), -25200);
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /forecast?latitude=32.2217&longitude=-110.9265&timezone=auto&forecast_days=1 "), "{}", requests[0]);
+    assert!(requests[1].starts_with("GET /compact?lat=32.2217&lon=-110.9265 "), "{}", requests[1]);
+}
+
+#[test]
+fn reuses_a_response_until_it_expires() {
+    let server = serve(vec![timezone(), compact("Fri, 01 Jan 2100 00:00:00 GMT")]);
+    let dir = scratch_dir("metno-expires");
+    let metno = metno(&server, &dir);
+
+    let first = metno.forecast(&tucson()).unwrap();
+    let second = metno.forecast(&tucson()).unwrap();
+
+    // the timezone is kept with the response, so neither is fetched again
+    assert_eq!(server.hits(), 2);
+    assert_eq!(second.location().timezone(), -25200);
+    assert_eq!(serde_json::to_string(&first).unwrap(), serde_json::to_string(&second).unwrap());
+}
+
+#[test]
+fn revalidates_an_expired_response() {
+    let not_modified = respond("304 Not Modified", &["Expires: Fri, 01 Jan 2100 00:00:00 GMT"], "");
+    let server = serve(vec![timezone(), compact("Sat, 18 Jun 2022 18:30:00 GMT"), not_modified]);
+    let dir = scratch_dir("metno-revalidate");
+    let metno = metno(&server, &dir);
+
+    let first = metno.forecast(&tucson()).unwrap();
+    let second = metno.forecast(&tucson()).unwrap();
+
+    let request = &server.requests()[2];
+    assert!(request.contains("If-Modified-Since: Sat, 18 Jun 2022 18:00:00 GMT"), "{}", request);
+    assert_eq!(serde_json::to_string(&first).unwrap(), serde_json::to_string(&second).unwrap());
+
+    // the 304 carried a new Expires, so the cached body is fresh again
+    metno.forecast(&tucson()).unwrap();
+    assert_eq!(server.hits(), 3);
+}
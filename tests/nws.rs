@@ -0,0 +1,23 @@
+mod common;
+
+use weathr::http::HttpClient;
+use weathr::nws::Nws;
+use weathr::provider::{Query, WeatherProvider};
+use weathr::request::{ApiError, Location, Units};
+
+use common::{respond, serve};
+
+#[test]
+fn explains_points_outside_the_us() {
+    let problem = r#"{"title":"Data Unavailable For Requested Point","status":404,"detail":"Unable to provide data for requested point 51.5074,-0.1278"}"#;
+    let server = serve(vec![respond("404 Not Found", &["Content-Type: application/problem+json"], problem)]);
+    let nws = Nws::new()
+        .with_base_url(server.url.trim_end_matches('/'), &format!("{}search", server.url))
+        .with_http(HttpClient::new().with_retries(0));
+
+    let error = nws.forecast(&Query::new(Location::Coordinates(51.5074, -0.1278), Units::Metric)).unwrap_err();
+
+    assert!(matches!(error, ApiError::NotCovered(ref point) if point == "51.5074,-0.1278"), "{:?}", error);
+    assert_eq!(error.hint(), Some("NWS only covers US locations, try --provider open-meteo"));
+    assert!(server.requests()[0].starts_with("GET /points/51.5074,-0.1278 "));
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use weathr::cache::ResponseCache;
+use weathr::geocode::Geocoder;
+use weathr::http::HttpClient;
+use weathr::mock_json::{JSON_CURRENT_RESPONSE, JSON_ONE_CALL_RESPONSE, JSON_RESPONSE};
+use weathr::openweathermap::OpenWeatherMap;
+use weathr::backend::Granularity;
+use weathr::provider::{Query, WeatherProvider};
+use weathr::request::{Location, Units};
+
+use common::{respond, serve, Server};
+
+fn json(body: &str) -> Option<String> {
+    respond("200 OK", &["Content-Type: application/json"], body)
+}
+
+// Reverse lookup naming the coordinates, the first request of each forecast
+fn tucson() -> Option<String> {
+    json(r#"[{"name":"Tucson","state":"Arizona","country":"US","lat":32.2217,"lon":-110.9265}]"#)
+}
+
+fn provider(server: &Server) -> OpenWeatherMap {
+    OpenWeatherMap::new(String::from("test-key"))
+        .with_geocoder(Geocoder::new(String::from("test-key")).with_base_url(&server.url).with_cache(None))
+        .with_base_url(&server.url)
+        .with_cache(ResponseCache::new().with_dir(None))
+        .with_http(HttpClient::new().with_retries(0))
+}
+
+fn query() -> Query {
+    Query::new(Location::Coordinates(32.2217, -110.9265), Units::Imperial)
+}
+
+#[test]
+fn three_hour_forecast_fetches_alerts_by_default() {
+    let server = serve(vec![tucson(), json(JSON_CURRENT_RESPONSE), json(JSON_RESPONSE), json(JSON_ONE_CALL_RESPONSE)]);
+
+    let forecast = provider(&server).forecast(&query()).unwrap();
+
+    assert_eq!(forecast.alerts().len(), 1);
+    assert!(forecast.has_active_alert(1655590000));
+    assert!(server.requests()[3].starts_with("GET /data/3.0/onecall?"));
+}
+
+#[test]
+fn three_hour_forecast_skips_alerts_without_one_call_access() {
+    let unauthorized = respond("401 Unauthorized", &[], r#"{"cod":401,"message":"Please note that using One Call 3.0 requires a separate subscription"}"#);
+    let server = serve(vec![tucson(), json(JSON_CURRENT_RESPONSE), json(JSON_RESPONSE), unauthorized]);
+
+    let forecast = provider(&server).forecast(&query()).unwrap();
+
+    assert!(forecast.alerts().is_empty());
+    assert_eq!(server.hits(), 4);
+}
+
+#[test]
+fn three_hour_forecast_skips_alerts_the_server_fails_to_send() {
+    let unavailable = respond("503 Service Unavailable", &[], "");
+    let server = serve(vec![tucson(), json(JSON_CURRENT_RESPONSE), json(JSON_RESPONSE), unavailable]);
+
+    let forecast = provider(&server).forecast(&query()).unwrap();
+
+    assert!(forecast.alerts().is_empty());
+    assert_eq!(server.hits(), 4);
+}
+
+#[test]
+fn no_alerts_drops_them_from_one_call_forecasts() {
+    let server = serve(vec![tucson(), json(JSON_CURRENT_RESPONSE), json(JSON_ONE_CALL_RESPONSE)]);
+    let mut query = query();
+    query.granularity = Granularity::Hourly;
+    query.alerts = false;
+
+    let forecast = provider(&server).forecast(&query).unwrap();
+
+    assert!(forecast.alerts().is_empty());
+    assert!(!forecast.has_active_alert(1655590000));
+}
+
+#[test]
+fn no_alerts_saves_the_one_call_request() {
+    let server = serve(vec![tucson(), json(JSON_CURRENT_RESPONSE), json(JSON_RESPONSE)]);
+    let mut query = query();
+    query.alerts = false;
+
+    let forecast = provider(&server).forecast(&query).unwrap();
+
+    assert!(forecast.alerts().is_empty());
+    assert_eq!(server.hits(), 3);
+}
+
+#[test]
+fn names_coordinates_after_the_nearest_place() {
+    let server = serve(vec![tucson(), json(JSON_CURRENT_RESPONSE), json(JSON_RESPONSE), json(JSON_ONE_CALL_RESPONSE)]);
+
+    let forecast = provider(&server).forecast(&query()).unwrap();
+
+    assert_eq!((forecast.location().name(), forecast.location().country()), ("Tucson, Arizona", "US"));
+    assert!(server.requests()[0].starts_with("GET /reverse?appid=test-key&lat=32.2217&lon=-110.9265&limit=1 "));
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use weathr::http::HttpClient;
+use weathr::request::ApiError;
+
+use common::{respond, serve};
+
+fn client(retries: u32) -> HttpClient {
+    HttpClient::new()
+        .with_timeouts(Duration::from_secs(1), Duration::from_millis(300))
+        .with_retries(retries)
+        .with_backoff(Duration::from_millis(10), Duration::from_millis(50))
+}
+
+fn ok() -> Option<String> { respond("200 OK", &[], "ok") }
+fn unavailable() -> Option<String> { respond("503 Service Unavailable", &[], "") }
+fn too_many() -> Option<String> { respond("429 Too Many Requests", &["Retry-After: 1"], "") }
+fn not_found() -> Option<String> { respond("404 Not Found", &[], "") }
+
+#[test]
+fn retries_server_errors_until_success() {
+    let server = serve(vec![unavailable(), unavailable(), ok()]);
+    let http = client(3);
+
+    let body = http.call(http.get(&server.url)).unwrap().into_string().unwrap();
+
+    assert_eq!(body, "ok");
+    assert_eq!(server.hits(), 3);
+}
+
+#[test]
+fn gives_up_after_retries_exhausted() {
+    let server = serve(vec![unavailable(); 4]);
+    let http = client(2);
+
+    let error = http.call(http.get(&server.url)).unwrap_err();
+
+    assert!(error.is_outage());
+    assert_eq!(server.hits(), 3);
+}
+
+#[test]
+fn waits_out_retry_after_on_rate_limit() {
+    let server = serve(vec![too_many(), ok()]);
+    let http = HttpClient::new()
+        .with_retries(1)
+        .with_backoff(Duration::from_millis(10), Duration::from_secs(5));
+    let start = Instant::now();
+
+    let body = http.call(http.get(&server.url)).unwrap().into_string().unwrap();
+
+    assert_eq!(body, "ok");
+    assert_eq!(server.hits(), 2);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[test]
+fn gives_up_when_retry_after_exceeds_the_longest_backoff() {
+    let server = serve(vec![too_many(), ok()]);
+    let http = client(3);
+    let start = Instant::now();
+
+    match http.call(http.get(&server.url)) {
+        Err(ApiError::Request(e)) => assert!(matches!(*e, ureq::Error::Status(429, _))),
+        other => panic!("expected a 429, got {:?}", other.map(|r| r.status())),
+    }
+
+    assert_eq!(server.hits(), 1);
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    let server = serve(vec![not_found(), ok()]);
+    let http = client(3);
+
+    match http.call(http.get(&server.url)) {
+        Err(ApiError::Request(e)) => assert!(matches!(*e, ureq::Error::Status(404, _))),
+        other => panic!("expected a 404, got {:?}", other.map(|r| r.status())),
+    }
+
+    assert_eq!(server.hits(), 1);
+}
+
+#[test]
+fn times_out_then_retries_a_stalled_response() {
+    let server = serve(vec![None, ok()]);
+    let http = client(1);
+    let start = Instant::now();
+
+    let body = http.call(http.get(&server.url)).unwrap().into_string().unwrap();
+
+    assert_eq!(body, "ok");
+    assert_eq!(server.hits(), 2);
+    assert!(start.elapsed() < Duration::from_secs(3));
+}